@@ -0,0 +1,87 @@
+//! Compares the word-at-a-time paths of [`BitSlice`] with a plain per-bit loop.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bitslicer::{BigEndian, BitOrder, BitSlice, ByteOrder, LittleEndian, Lsb0, Msb0};
+
+const ITERATIONS: u32 = 2_000;
+
+/// Runs `f` repeatedly and returns the average time per run.
+fn time(mut f: impl FnMut()) -> Duration {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn report(name: &str, per_bit: Duration, fast: Duration) {
+    println!(
+        "{name:<40} per-bit {:>10.2?}  fast {:>10.2?}  speedup {:>6.1}x",
+        per_bit,
+        fast,
+        per_bit.as_secs_f64() / fast.as_secs_f64()
+    );
+}
+
+fn per_bit_eq<B: BitOrder, E: ByteOrder>(
+    a: &BitSlice<&[u8], B, E>,
+    b: &BitSlice<&[u8], B, E>,
+) -> bool {
+    a.len() == b.len() && (0..a.len()).all(|i| a.get_bit(i) == b.get_bit(i))
+}
+
+fn per_bit_u64<B: BitOrder, E: ByteOrder>(a: &BitSlice<&[u8], B, E>) -> u64 {
+    (0..a.len())
+        .rev()
+        .fold(0, |v, i| (v << 1) | a.get_bit(i) as u64)
+}
+
+fn bench<B: BitOrder + Default, E: ByteOrder + Default>(label: &str) {
+    let data: Vec<u8> = (0..4096u32).map(|i| (i * 37 + 11) as u8).collect();
+    let copy = data.clone();
+    let a: BitSlice<&[u8], B, E> = data.as_slice().into();
+    let b: BitSlice<&[u8], B, E> = copy.as_slice().into();
+
+    for (kind, start) in [("aligned", 0), ("unaligned", 3)] {
+        let x = a.slice(start..a.len() - 5);
+        let y = b.slice(start..b.len() - 5);
+        report(
+            &format!("{label} eq {kind}"),
+            time(|| assert!(per_bit_eq(black_box(&x), black_box(&y)))),
+            time(|| assert!(black_box(&x) == black_box(&y))),
+        );
+        report(
+            &format!("{label} iter {kind}"),
+            time(|| {
+                black_box((0..x.len()).filter(|&i| x.get_bit(i)).count());
+            }),
+            time(|| {
+                black_box(black_box(&x).iter().filter(|&b| b).count());
+            }),
+        );
+        let field = a.slice(start..start + 48);
+        report(
+            &format!("{label} to_u64 {kind}"),
+            time(|| {
+                black_box(per_bit_u64(black_box(&field)));
+            }),
+            time(|| {
+                black_box(black_box(&field).to_u64());
+            }),
+        );
+    }
+}
+
+fn main() {
+    bench::<Lsb0, LittleEndian>("Lsb0/LittleEndian");
+    bench::<Lsb0, BigEndian>("Lsb0/BigEndian");
+    bench::<Msb0, LittleEndian>("Msb0/LittleEndian");
+    bench::<Msb0, BigEndian>("Msb0/BigEndian");
+}
@@ -0,0 +1,310 @@
+//! # bitslicer-derive
+//!
+//! This crate provides `#[derive(BitPack)]`, which implements `bitslicer::BitPack` for structs
+//! whose fields live at fixed bit ranges and for fieldless enums. It is re-exported by
+//! `bitslicer` with the `derive` feature, see the documentation of `bitslicer::BitPack` for the
+//! supported attributes.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Expr, ExprLit, ExprRange,
+    Fields, Ident, Lit, RangeLimits,
+};
+
+/// Derives `bitslicer::BitPack` for a struct with `#[bits(..)]` fields or a fieldless enum.
+#[proc_macro_derive(BitPack, attributes(bits, bitpack))]
+pub fn derive_bit_pack(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A range of bits given by an attribute, together with the span to report errors at.
+struct BitRange {
+    start: usize,
+    end: usize,
+    span: Span,
+}
+
+/// The options of the `#[bitpack(..)]` attribute of the type.
+#[derive(Default)]
+struct Options {
+    order: Option<Ident>,
+    endian: Option<Ident>,
+    reserved: Vec<BitRange>,
+}
+
+/// Parses an integer literal.
+fn parse_index(expr: &Expr) -> syn::Result<usize> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse(),
+        _ => Err(Error::new(expr.span(), "expected an integer literal")),
+    }
+}
+
+/// Parses a range of bits, written as `start..end`, `start..=end` or as the index of a single bit.
+fn parse_range(expr: &Expr) -> syn::Result<BitRange> {
+    let (start, end) = match expr {
+        Expr::Range(ExprRange {
+            start: Some(start),
+            end: Some(end),
+            limits,
+            ..
+        }) => {
+            let (start, end) = (parse_index(start)?, parse_index(end)?);
+            match limits {
+                RangeLimits::HalfOpen(_) => (start, end),
+                RangeLimits::Closed(_) => (start, end + 1),
+            }
+        }
+        Expr::Range(_) => return Err(Error::new(expr.span(), "expected a bounded range")),
+        _ => {
+            let index = parse_index(expr)?;
+            (index, index + 1)
+        }
+    };
+    if start >= end {
+        return Err(Error::new(expr.span(), "bit range is empty"));
+    }
+    Ok(BitRange {
+        start,
+        end,
+        span: expr.span(),
+    })
+}
+
+fn parse_options(attrs: &[Attribute]) -> syn::Result<Options> {
+    let mut options = Options::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("bitpack")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("order") {
+                options.order = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("endian") {
+                options.endian = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("reserved") {
+                options.reserved.push(parse_range(&meta.value()?.parse()?)?);
+            } else {
+                return Err(meta.error("expected `order`, `endian` or `reserved`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+/// Returns the range of the `#[bits(..)]` attribute of a field.
+fn field_range(attrs: &[Attribute], span: Span) -> syn::Result<BitRange> {
+    let mut ranges = attrs.iter().filter(|attr| attr.path().is_ident("bits"));
+    let attr = ranges
+        .next()
+        .ok_or_else(|| Error::new(span, "missing `#[bits(..)]` attribute"))?;
+    if let Some(extra) = ranges.next() {
+        return Err(Error::new(
+            extra.span(),
+            "duplicate `#[bits(..)]` attribute",
+        ));
+    }
+    parse_range(&attr.parse_args()?)
+}
+
+/// Returns whether the `#[repr(..)]` attribute of an enum gives an unsigned discriminant type.
+fn unsigned_repr(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut unsigned = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            unsigned |= ["u8", "u16", "u32", "u64", "u128", "usize"]
+                .iter()
+                .any(|ty| meta.path.is_ident(ty));
+            Ok(())
+        })?;
+    }
+    Ok(unsigned)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let options = parse_options(&input.attrs)?;
+    let name = &input.ident;
+    let order = match &options.order {
+        Some(order) => quote!(::bitslicer::#order),
+        None => quote!(__B),
+    };
+    let endian = match &options.endian {
+        Some(endian) => quote!(::bitslicer::#endian),
+        None => quote!(__E),
+    };
+
+    let mut generics = input.generics.clone();
+    if options.order.is_none() {
+        generics
+            .params
+            .push(parse_quote!(__B: ::bitslicer::BitOrder));
+    }
+    if options.endian.is_none() {
+        generics
+            .params
+            .push(parse_quote!(__E: ::bitslicer::ByteOrder));
+    }
+    let where_clause = generics.make_where_clause();
+
+    let mut checks = TokenStream::new();
+    let (pack, unpack) = match &input.data {
+        Data::Struct(data) => {
+            for field in &data.fields {
+                let ty = &field.ty;
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::bitslicer::BitPack<#order, #endian>));
+            }
+            expand_struct(&data.fields, &options, &order, &endian)?
+        }
+        Data::Enum(data) => {
+            if !options.reserved.is_empty() {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`reserved` is not supported for enums",
+                ));
+            }
+            let mut variants = Vec::new();
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new(
+                        variant.span(),
+                        "`BitPack` only supports enums without fields",
+                    ));
+                }
+                variants.push(&variant.ident);
+            }
+            // Discriminants are packed as `u128`, which would sign-extend negative ones.
+            if !unsigned_repr(&input.attrs)? {
+                checks = quote! {
+                    #(
+                        const _: () = ::core::assert!(
+                            #name::#variants as i128 >= 0,
+                            ::core::concat!(
+                                "`BitPack` does not support the negative discriminant of `",
+                                ::core::stringify!(#variants),
+                                "`"
+                            )
+                        );
+                    )*
+                };
+            }
+            let pack = quote! {
+                let value: u128 = match self {
+                    #(Self::#variants => Self::#variants as u128,)*
+                };
+                bits.set_field(.., value)
+            };
+            let unpack = quote! {
+                let value: u128 = bits.get_field(..)?;
+                #(
+                    if value == Self::#variants as u128 {
+                        return Ok(Self::#variants);
+                    }
+                )*
+                Err(::bitslicer::Error::ConversionError)
+            };
+            (pack, unpack)
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`BitPack` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::bitslicer::BitPack<#order, #endian> for #name #ty_generics
+        #where_clause
+        {
+            fn pack<__S: ::bitslicer::BitStorageMut>(
+                &self,
+                bits: &mut ::bitslicer::BitSlice<__S, #order, #endian>,
+            ) -> ::core::result::Result<(), ::bitslicer::Error> {
+                #pack
+            }
+            fn unpack<__S: ::bitslicer::BitStorage>(
+                bits: &::bitslicer::BitSlice<__S, #order, #endian>,
+            ) -> ::core::result::Result<Self, ::bitslicer::Error> {
+                #unpack
+            }
+        }
+        #checks
+    })
+}
+
+fn expand_struct(
+    fields: &Fields,
+    options: &Options,
+    order: &TokenStream,
+    endian: &TokenStream,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut ranges = Vec::new();
+    for field in fields {
+        ranges.push(field_range(&field.attrs, field.span())?);
+    }
+    // Fields and reserved bits must not share any bit.
+    let all: Vec<&BitRange> = ranges.iter().chain(&options.reserved).collect();
+    for (i, a) in all.iter().enumerate() {
+        for b in &all[..i] {
+            if a.start < b.end && b.start < a.end {
+                return Err(Error::new(
+                    a.span,
+                    format!(
+                        "bits {}..{} overlap bits {}..{}",
+                        a.start, a.end, b.start, b.end
+                    ),
+                ));
+            }
+        }
+    }
+    let len = all.iter().map(|range| range.end).max().unwrap_or(0);
+
+    let mut pack = Vec::new();
+    let mut unpack = Vec::new();
+    for (i, (field, range)) in fields.iter().zip(&ranges).enumerate() {
+        let (start, end) = (range.start, range.end);
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(i);
+                quote!(#index)
+            }
+        };
+        let span = field.ty.span();
+        pack.push(quote_spanned! {span=>
+            ::bitslicer::BitPack::<#order, #endian>::pack(
+                &self.#member,
+                &mut bits.try_slice_mut(#start..#end)?,
+            )?;
+        });
+        unpack.push(quote_spanned! {span=>
+            #member: ::bitslicer::BitPack::<#order, #endian>::unpack(
+                &bits.try_slice(#start..#end)?,
+            )?
+        });
+    }
+    let reserved = options.reserved.iter().map(|range| {
+        let (start, end) = (range.start, range.end);
+        quote!(bits.try_slice_mut(#start..#end)?.set_field(.., 0u8)?;)
+    });
+    let pack = quote! {
+        bits.try_slice_mut(..#len)?;
+        #(#pack)*
+        #(#reserved)*
+        Ok(())
+    };
+    let unpack = quote! {
+        bits.try_slice(..#len)?;
+        Ok(Self { #(#unpack,)* })
+    };
+    Ok((pack, unpack))
+}
@@ -0,0 +1,114 @@
+use bitslicer::{BigEndian, BitPack, BitSlice, Error, LittleEndian, Lsb0, Msb0};
+
+#[derive(BitPack, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Kind {
+    Data = 0,
+    Ack = 2,
+    Nack = 3,
+}
+
+#[derive(BitPack, Debug, PartialEq)]
+struct Flags(#[bits(0)] bool, #[bits(1..=2)] Kind);
+
+#[derive(BitPack, Debug, PartialEq)]
+#[bitpack(reserved = 3..4)]
+struct Header {
+    #[bits(0..3)]
+    flags: Flags,
+    #[bits(4..17)]
+    length: u16,
+    #[bits(17..24)]
+    delta: i8,
+}
+
+#[derive(BitPack, Debug, PartialEq)]
+#[bitpack(order = Msb0, endian = LittleEndian, reserved = 0..2, reserved = 30..32)]
+struct Register {
+    #[bits(2..6)]
+    channel: u8,
+    #[bits(6..30)]
+    counter: u32,
+}
+
+#[test]
+fn test_nested() {
+    let header = Header {
+        flags: Flags(true, Kind::Nack),
+        length: 0x1abc,
+        delta: -37,
+    };
+    let mut data = [0xffu8; 4];
+    let mut bits: BitSlice<_, Lsb0, LittleEndian> = data.as_mut().into();
+    header.pack(&mut bits).unwrap();
+    assert_eq!(Header::unpack(&bits), Ok(header));
+    // The reserved bit 3 is cleared and the bits after the header are not touched.
+    assert_eq!(data, [0xc7, 0xab, 0xb7, 0xff]);
+}
+
+#[test]
+fn test_nested_msb0_big_endian() {
+    let header = Header {
+        flags: Flags(false, Kind::Ack),
+        length: 0x1abc,
+        delta: -37,
+    };
+    let mut data = [0xffu8; 4];
+    let mut bits: BitSlice<_, Msb0, BigEndian> = data.as_mut().into();
+    header.pack(&mut bits).unwrap();
+    assert_eq!(bits.get_field::<u8>(..4), Ok(0b0100));
+    assert_eq!(Header::unpack(&bits), Ok(header));
+    assert_eq!(data, [0xff, 0x5b, 0x5e, 0x4d]);
+}
+
+#[test]
+fn test_fixed_order() {
+    let register = Register {
+        channel: 0xa,
+        counter: 0x12_3456,
+    };
+    let mut data = [0xffu8; 4];
+    register.pack(&mut BitSlice::from(data.as_mut())).unwrap();
+    assert_eq!(data, [0b0010_1000, 0x48, 0xd1, 0x58]);
+    assert_eq!(
+        Register::unpack(&BitSlice::from(data.as_ref())),
+        Ok(register)
+    );
+}
+
+#[test]
+fn test_errors() {
+    let mut data = [0u8; 2];
+    let mut bits: BitSlice<_> = data.as_mut().into();
+    let header = Header {
+        flags: Flags(false, Kind::Data),
+        length: 0,
+        delta: 0,
+    };
+    assert_eq!(
+        header.pack(&mut bits),
+        Err(Error::RangeOutOfBounds {
+            start: 0,
+            end: 24,
+            len: 16
+        })
+    );
+    assert!(Header::unpack(&bits).is_err());
+
+    let mut data = [0u8; 3];
+    let mut bits: BitSlice<_> = data.as_mut().into();
+    let header = Header {
+        flags: Flags(false, Kind::Data),
+        length: 0x2000,
+        delta: 0,
+    };
+    assert_eq!(header.pack(&mut bits), Err(Error::Overflow));
+    // `1` is not a discriminant of `Kind`.
+    bits.set_field(1..3, 1u8).unwrap();
+    assert_eq!(Header::unpack(&bits), Err(Error::ConversionError));
+    assert_eq!(Kind::unpack(&bits.slice(0..1)), Ok(Kind::Data));
+    assert_eq!(
+        Kind::Ack.pack(&mut bits.slice_mut(0..1)),
+        Err(Error::Overflow)
+    );
+}
@@ -0,0 +1,295 @@
+use core::ops::{Deref, RangeBounds};
+
+use crate::{
+    BitIterMut, BitOrder, BitSlice, ByteOrder, ConstBitOrder, ConstByteOrder, Error, LittleEndian,
+    Lsb0, Signed, Unsigned,
+};
+
+/// A fixed-size sequence of `BITS` bits stored inline in a `[u8; BYTES]`.
+///
+/// The length is known at compile time, so a [BitArray] can be built in a `const` or `static`
+/// and kept on the stack without any allocation. It dereferences to a [`BitSlice`] over its
+/// storage, which provides the rest of the read-only API.
+///
+/// The length of a [BitArray] never changes, so it does not dereference mutably, as that would
+/// make [`BitSlice::push`] and [`BitSlice::pop`] available. The common setters are provided on
+/// the array itself and [`BitArray::as_mut_bitslice`] gives access to all other mutating methods.
+///
+/// ```compile_fail
+/// use bitslicer::BitArray;
+/// let mut array: BitArray<12, 2> = BitArray::default();
+/// array.push(true).unwrap();
+/// ```
+///
+/// Stable Rust cannot compute the storage size from `BITS`, so it has to be given as `BYTES`.
+/// It must be equal to `BITS.div_ceil(8)` and consist of whole words of the byte order, which is
+/// checked at compile time. The [`bitarr!`](crate::bitarr) macro names the type of an array
+/// from `BITS` alone.
+///
+/// ```compile_fail
+/// use bitslicer::BitArray;
+/// let too_small: BitArray<12, 1> = BitArray::default();
+/// ```
+///
+/// ```compile_fail
+/// use bitslicer::{BitArray, Lsb0, Words};
+/// let partial: BitArray<12, 2, Lsb0, Words<u32>> = BitArray::default();
+/// ```
+///
+/// # Type Parameters
+/// - `BITS`: The number of bits.
+/// - `BYTES`: The number of bytes of the storage.
+/// - `B`: The bit order, which dictates the order in which bits are read.
+/// - `Endian`: The byte order, which dictates the order in which bytes are read.
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BigEndian, BitArray, Msb0, bitarr};
+///
+/// const RESET: bitarr!(12, Msb0, BigEndian) = BitArray::new([0x0a, 0x50]);
+///
+/// let mut reg = RESET;
+/// assert_eq!(reg.len(), 12);
+/// reg.set_field(0..4, 0xfu8).unwrap();
+/// assert_eq!(reg.into_bytes(), [0x0a, 0xf0]);
+/// ```
+pub struct BitArray<const BITS: usize, const BYTES: usize, B = Lsb0, Endian = LittleEndian> {
+    slice: BitSlice<[u8; BYTES], B, Endian>,
+}
+
+impl<const BITS: usize, const BYTES: usize, B, Endian: ByteOrder> BitArray<BITS, BYTES, B, Endian> {
+    const WORD_CHECK: () = assert!(
+        BYTES.is_multiple_of(Endian::WORD_BYTES),
+        "BYTES must be a whole number of words"
+    );
+}
+
+impl<const BITS: usize, const BYTES: usize, B, Endian> BitArray<BITS, BYTES, B, Endian> {
+    /// The number of bits in the array.
+    pub const LEN: usize = BITS;
+    const CHECK: () = assert!(BYTES == BITS.div_ceil(8), "BYTES must be BITS.div_ceil(8)");
+
+    /// Creates a new [BitArray] from its storage with a bit and byte order that is fixed at
+    /// compile time. Arrays with other orders are created with [`BitArray::new_with_order`] or
+    /// [`From`].
+    ///
+    /// # Arguments
+    /// * `bytes` - The underlying storage for the bits.
+    #[inline(always)]
+    pub const fn new(bytes: [u8; BYTES]) -> Self
+    where
+        B: ConstBitOrder,
+        Endian: ConstByteOrder,
+    {
+        Self::new_with_order(bytes, B::ORDER, Endian::ORDER)
+    }
+    /// Creates a new [BitArray] from its storage with specific bit and byte order.
+    ///
+    /// # Arguments
+    /// * `bytes` - The underlying storage for the bits.
+    /// * `bit_order` - The bit order to use.
+    /// * `endianness` - The byte order to use.
+    #[inline(always)]
+    pub const fn new_with_order(bytes: [u8; BYTES], bit_order: B, endianness: Endian) -> Self
+    where
+        Endian: ByteOrder,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK;
+        #[allow(clippy::let_unit_value)]
+        let () = Self::WORD_CHECK;
+        Self {
+            slice: BitSlice {
+                bytes,
+                range: 0..BITS,
+                bit_order,
+                byte_order: endianness,
+            },
+        }
+    }
+    /// Returns the storage of the array.
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.slice.bytes
+    }
+    /// Returns the storage of the array mutably.
+    #[inline(always)]
+    pub fn as_mut_bytes(&mut self) -> &mut [u8; BYTES] {
+        &mut self.slice.bytes
+    }
+    /// Consumes the array and returns its storage.
+    #[inline(always)]
+    pub fn into_bytes(self) -> [u8; BYTES] {
+        self.slice.bytes
+    }
+    /// Returns a [BitSlice] over all bits of the array that borrows the storage.
+    #[inline(always)]
+    pub fn as_bitslice(&self) -> BitSlice<&[u8], B, Endian>
+    where
+        B: Copy,
+        Endian: Copy,
+    {
+        self.slice.slice(..)
+    }
+    /// Returns a mutable [BitSlice] over all bits of the array that borrows the storage.
+    #[inline(always)]
+    pub fn as_mut_bitslice(&mut self) -> BitSlice<&mut [u8], B, Endian>
+    where
+        B: Copy,
+        Endian: Copy,
+    {
+        BitSlice {
+            bytes: &mut self.slice.bytes,
+            range: self.slice.range.clone(),
+            bit_order: self.slice.bit_order,
+            byte_order: self.slice.byte_order,
+        }
+    }
+}
+
+impl<const BITS: usize, const BYTES: usize, B: BitOrder, Endian: ByteOrder>
+    BitArray<BITS, BYTES, B, Endian>
+{
+    /// Sets the value of a bit at a specified index, see [`BitSlice::set_bit`].
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds. See [`BitArray::try_set_bit`] for a non-panicking
+    /// version.
+    #[inline(always)]
+    #[track_caller]
+    pub fn set_bit(&mut self, n: usize, value: bool) {
+        self.slice.set_bit(n, value)
+    }
+    /// Sets the value of a bit at a specified index, see [`BitSlice::try_set_bit`].
+    #[inline(always)]
+    pub fn try_set_bit(&mut self, n: usize, value: bool) -> Result<(), Error> {
+        self.slice.try_set_bit(n, value)
+    }
+    /// Returns a mutable [BitSlice] over a range of bits, see [`BitSlice::slice_mut`].
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds. See [`BitArray::try_slice_mut`] for a non-panicking
+    /// version.
+    #[inline(always)]
+    #[track_caller]
+    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> BitSlice<&mut [u8], B, Endian> {
+        self.slice.slice_mut(range)
+    }
+    /// Returns a mutable [BitSlice] over a range of bits, see [`BitSlice::try_slice_mut`].
+    #[inline(always)]
+    pub fn try_slice_mut(
+        &mut self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<BitSlice<&mut [u8], B, Endian>, Error> {
+        self.slice.try_slice_mut(range)
+    }
+    /// Writes an unsigned integer to the bits in `range`, see [`BitSlice::set_field`].
+    #[inline(always)]
+    pub fn set_field<T: Unsigned>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        self.slice.set_field(range, value)
+    }
+    /// Writes a two's complement signed integer to the bits in `range`, see
+    /// [`BitSlice::set_signed_field`].
+    #[inline(always)]
+    pub fn set_signed_field<T: Signed>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        self.slice.set_signed_field(range, value)
+    }
+    /// Returns an [`Iterator`] over mutable references to all bits, see [`BitSlice::iter_mut`].
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> BitIterMut<'_, B, Endian> {
+        self.slice.iter_mut()
+    }
+}
+
+impl<const BITS: usize, const BYTES: usize, B, Endian> Deref for BitArray<BITS, BYTES, B, Endian> {
+    type Target = BitSlice<[u8; BYTES], B, Endian>;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.slice
+    }
+}
+
+impl<const BITS: usize, const BYTES: usize, B: Default, Endian: ByteOrder + Default> Default
+    for BitArray<BITS, BYTES, B, Endian>
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::from([0; BYTES])
+    }
+}
+
+impl<const BITS: usize, const BYTES: usize, B: Default, Endian: ByteOrder + Default>
+    From<[u8; BYTES]> for BitArray<BITS, BYTES, B, Endian>
+{
+    #[inline(always)]
+    fn from(bytes: [u8; BYTES]) -> Self {
+        Self::new_with_order(bytes, Default::default(), Default::default())
+    }
+}
+
+impl<const BITS: usize, const BYTES: usize, B: Copy, Endian: Copy> Clone
+    for BitArray<BITS, BYTES, B, Endian>
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BigEndian, Msb0};
+
+    static PATTERN: BitArray<10, 2, Msb0, BigEndian> =
+        BitArray::new_with_order([0b1000_0000, 0b1100_0000], Msb0, BigEndian);
+    const FLAGS: crate::bitarr!(20) = BitArray::new([0x01, 0x00, 0x08]);
+
+    #[test]
+    fn test_static() {
+        assert_eq!(PATTERN.len(), 10);
+        assert_eq!(PATTERN.get_field::<u16>(..), Ok(0b11_0000_0010));
+        assert_eq!(BitArray::<10, 2, Msb0, BigEndian>::LEN, 10);
+        assert_eq!(FLAGS.as_bytes().len(), 3);
+        assert!(FLAGS.get_bit(0) && FLAGS.get_bit(19));
+        let words: crate::bitarr!(64, Lsb0, crate::Words<u32>) = BitArray::default();
+        assert_eq!(words.as_bytes().len(), 8);
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut array: BitArray<20, 3> = BitArray::default();
+        array.set_bit(19, true);
+        array.set_field(0..8, 0xa5u8).unwrap();
+        array.as_mut_bitslice().set_bit(8, true);
+        assert_eq!(array.as_bytes(), &[0xa5, 0x01, 0x08]);
+        assert!(array.as_bitslice() == *array);
+        let copy = array.clone();
+        array.as_mut_bytes()[1] = 0;
+        assert!(!array.get_bit(8));
+        assert!(copy.get_bit(8));
+    }
+
+    #[test]
+    fn test_fixed_length() {
+        let mut array: BitArray<12, 2, Msb0, BigEndian> = BitArray::default();
+        let mut bits = array.as_mut_bitslice();
+        assert_eq!(bits.len(), 12);
+        assert_eq!(bits.pop(), Some(false));
+        assert_eq!(bits.len(), 11);
+        array.slice_mut(4..).set_field(.., 0xffu8).unwrap();
+        assert_eq!(array.len(), 12);
+        assert_eq!(array.as_bitslice().len(), 12);
+        assert_eq!(array.as_bytes(), &[0xf0, 0x0f]);
+    }
+}
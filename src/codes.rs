@@ -0,0 +1,348 @@
+use crate::{low_mask, BitOrder, BitReader, BitStorage, BitWriter, ByteOrder, Error, WriteStorage};
+
+/// Maps a signed Exp-Golomb value to its code number, i.e. `0, 1, -1, 2, -2, ...` to
+/// `0, 1, 2, 3, 4, ...`.
+#[inline(always)]
+fn signed_to_code(value: i64) -> u128 {
+    if value > 0 {
+        value as u128 * 2 - 1
+    } else {
+        value.unsigned_abs() as u128 * 2
+    }
+}
+
+/// The inverse of [`signed_to_code`].
+#[inline(always)]
+fn code_to_signed(code: u128) -> Result<i64, Error> {
+    let magnitude = code.div_ceil(2) as i128;
+    let value = if code % 2 == 1 { magnitude } else { -magnitude };
+    i64::try_from(value).map_err(|_| Error::ConversionError)
+}
+
+/// Variable-length codes as used by H.264/H.265 (`ue(v)`, `se(v)`) and lossless audio codecs.
+///
+/// The zeros of a unary prefix are read in stream order, while the suffix of a code is read like
+/// [`BitReader::read`] reads an integer. Bitstreams such as H.264 are therefore read with
+/// [`Msb0`](crate::Msb0).
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> BitReader<S, B, Endian> {
+    /// Counts the zeros before the next set bit without advancing the position.
+    fn peek_zeros(&self, limit: usize) -> Result<usize, Error> {
+        let rest = self.slice.slice(self.pos..);
+        let window = rest.slice(..rest.len().min(limit));
+        match window.first_one() {
+            Some(zeros) => Ok(zeros),
+            None if window.len() < rest.len() => Err(Error::ConversionError),
+            None => Err(Error::EndOfData {
+                requested: rest.len() + 1,
+                remaining: rest.len(),
+            }),
+        }
+    }
+    /// Reads an order-0 Exp-Golomb code and returns its code number and length.
+    fn peek_exp_golomb(&self) -> Result<(u128, usize), Error> {
+        // More than 64 leading zeros encode a code number above `2^65 - 2`.
+        let zeros = self.peek_zeros(65)?;
+        let range = self.take(2 * zeros + 1)?;
+        let info: u64 = self.slice.get_field(range.start + zeros + 1..range.end)?;
+        Ok(((1u128 << zeros) - 1 + info as u128, range.len()))
+    }
+    /// Reads a unary code, that is the number of zeros before the next set bit, and the set bit.
+    ///
+    /// # Returns
+    /// The number of zeros, or [`Error::EndOfData`] if no bit after the position is set.
+    pub fn read_unary(&mut self) -> Result<u64, Error> {
+        let zeros = self.peek_zeros(usize::MAX)?;
+        self.pos += zeros + 1;
+        Ok(zeros as u64)
+    }
+    /// Reads an unsigned order-0 Exp-Golomb code, `ue(v)` in H.264.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if the code is cut off or [`Error::ConversionError`] if
+    /// the value does not fit into a `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitReader, BitSlice, LittleEndian, Msb0};
+    /// let data = [0b1_010_0110u8, 0b0101_0000];
+    /// let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+    /// let mut reader = BitReader::new(bits);
+    /// assert_eq!(reader.read_ue(), Ok(0));
+    /// assert_eq!(reader.read_ue(), Ok(1));
+    /// assert_eq!(reader.read_ue(), Ok(2));
+    /// assert_eq!(reader.read_se(), Ok(-2));
+    /// ```
+    pub fn read_ue(&mut self) -> Result<u64, Error> {
+        let (code, len) = self.peek_exp_golomb()?;
+        let value = u64::try_from(code).map_err(|_| Error::ConversionError)?;
+        self.pos += len;
+        Ok(value)
+    }
+    /// Reads a signed order-0 Exp-Golomb code, `se(v)` in H.264.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if the code is cut off or [`Error::ConversionError`] if
+    /// the value does not fit into an `i64`.
+    pub fn read_se(&mut self) -> Result<i64, Error> {
+        let (code, len) = self.peek_exp_golomb()?;
+        let value = code_to_signed(code)?;
+        self.pos += len;
+        Ok(value)
+    }
+    /// Reads a Golomb-Rice code with parameter `k`: the quotient `value >> k` as a unary code,
+    /// followed by the low `k` bits of the value.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if the code is cut off or [`Error::ConversionError`] if
+    /// the value does not fit into a `u64`.
+    pub fn read_rice(&mut self, k: u32) -> Result<u64, Error> {
+        let quotient = self.peek_zeros(usize::MAX)?;
+        let range = self.take(quotient + 1 + k as usize)?;
+        let remainder: u64 = self
+            .slice
+            .get_field(range.start + quotient + 1..range.end)?;
+        if quotient != 0 && (k >= 64 || quotient as u64 > u64::MAX >> k) {
+            return Err(Error::ConversionError);
+        }
+        self.pos = range.end;
+        Ok(((quotient as u64) << (k % 64)) | remainder)
+    }
+}
+
+/// Variable-length codes as used by H.264/H.265 (`ue(v)`, `se(v)`) and lossless audio codecs.
+///
+/// These write the codes read by the corresponding methods of [`BitReader`].
+impl<S: WriteStorage, B: BitOrder, Endian: ByteOrder> BitWriter<S, B, Endian> {
+    /// Writes `zeros` zeros, a set bit and `suffix` as an integer of `n` bits.
+    fn write_prefixed(&mut self, zeros: u64, suffix: u64, n: usize) -> Result<(), Error> {
+        let zeros = usize::try_from(zeros).map_err(|_| Error::Overflow)?;
+        let len = zeros.checked_add(1 + n).ok_or(Error::Overflow)?;
+        let pos = self.pos;
+        let mut slice = self.reserve(len)?;
+        slice.set_field(pos + zeros + 1..pos + len, suffix)?;
+        slice.fill_bits(pos..pos + zeros, false);
+        slice.set_bit(pos + zeros, true);
+        self.pos += len;
+        Ok(())
+    }
+    /// Writes an order-0 Exp-Golomb code for the code number `code`.
+    fn write_exp_golomb(&mut self, code: u128) -> Result<(), Error> {
+        let code = code + 1;
+        let zeros = 127 - code.leading_zeros();
+        self.write_prefixed(zeros as u64, (code - (1 << zeros)) as u64, zeros as usize)
+    }
+    /// Writes `value` as a unary code, that is `value` zeros followed by a set bit.
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has fewer than `value + 1` bits left.
+    pub fn write_unary(&mut self, value: u64) -> Result<(), Error> {
+        self.write_prefixed(value, 0, 0)
+    }
+    /// Writes `value` as an unsigned order-0 Exp-Golomb code, `ue(v)` in H.264.
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has too few bits left for the code.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitWriter, LittleEndian, Msb0};
+    /// let mut data = [0u8; 2];
+    /// let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new(data.as_mut());
+    /// writer.write_ue(0).unwrap();
+    /// writer.write_ue(1).unwrap();
+    /// writer.write_ue(2).unwrap();
+    /// writer.write_se(-2).unwrap();
+    /// assert_eq!(writer.finish(), 2);
+    /// assert_eq!(data, [0b1_010_0110u8, 0b0101_0000]);
+    /// ```
+    pub fn write_ue(&mut self, value: u64) -> Result<(), Error> {
+        self.write_exp_golomb(value as u128)
+    }
+    /// Writes `value` as a signed order-0 Exp-Golomb code, `se(v)` in H.264.
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has too few bits left for the code.
+    pub fn write_se(&mut self, value: i64) -> Result<(), Error> {
+        self.write_exp_golomb(signed_to_code(value))
+    }
+    /// Writes `value` as a Golomb-Rice code with parameter `k`.
+    ///
+    /// See [`BitReader::read_rice`].
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has too few bits left for the code.
+    pub fn write_rice(&mut self, value: u64, k: u32) -> Result<(), Error> {
+        let quotient = value.checked_shr(k).unwrap_or(0);
+        let remainder = value & low_mask(k as usize);
+        self.write_prefixed(quotient, remainder, k as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    extern crate alloc;
+    extern crate std;
+
+    /// `ue(v)` and `se(v)` code words from tables 9-2 and 9-3 of the H.264 specification.
+    const EXP_GOLOMB: [(&str, u64, i64); 10] = [
+        ("1", 0, 0),
+        ("010", 1, 1),
+        ("011", 2, -1),
+        ("00100", 3, 2),
+        ("00101", 4, -2),
+        ("00110", 5, 3),
+        ("00111", 6, -3),
+        ("0001000", 7, 4),
+        ("0001110", 13, 7),
+        ("000011111", 30, -15),
+    ];
+
+    fn write_str<S: WriteStorage>(writer: &mut BitWriter<S, Msb0, LittleEndian>, code: &str) {
+        for c in code.chars() {
+            writer.write_bit(c == '1').unwrap();
+        }
+    }
+
+    #[test]
+    fn test_exp_golomb_vectors() {
+        for (code, ue, se) in EXP_GOLOMB {
+            let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new([0u8; 2]);
+            write_str(&mut writer, code);
+            let expected = writer.into_inner();
+
+            let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new([0u8; 2]);
+            writer.write_ue(ue).unwrap();
+            assert_eq!(writer.position(), code.len());
+            assert_eq!(writer.into_inner(), expected);
+            let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new([0u8; 2]);
+            writer.write_se(se).unwrap();
+            assert_eq!(writer.into_inner(), expected);
+
+            let bits: BitSlice<_, Msb0, LittleEndian> =
+                BitSlice::new(expected.as_slice(), code.len());
+            assert_eq!(BitReader::new(bits.slice(..)).read_ue(), Ok(ue));
+            assert_eq!(BitReader::new(bits.slice(..)).read_se(), Ok(se));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = [
+            0,
+            1,
+            2,
+            5,
+            63,
+            64,
+            1000,
+            u32::MAX as u64,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        let signed = [0, 1, -1, 100, -100, i64::MAX, i64::MIN];
+        let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new([0u8; 512]);
+        writer.write_bit(true).unwrap();
+        for v in values {
+            writer.write_ue(v).unwrap();
+            writer.write_rice(v, 60).unwrap();
+            writer.write_rice(v & 0xff, 3).unwrap();
+        }
+        for v in signed {
+            writer.write_se(v).unwrap();
+        }
+        writer.write_unary(0).unwrap();
+        writer.write_unary(200).unwrap();
+        writer.write_rice(u64::MAX, 64).unwrap();
+        writer.write_rice(7, 100).unwrap();
+        let len = writer.position();
+        let data = writer.into_inner();
+
+        let mut reader =
+            BitReader::new(BitSlice::<_, Msb0, LittleEndian>::new(data.as_slice(), len));
+        assert_eq!(reader.read_bit(), Ok(true));
+        for v in values {
+            assert_eq!(reader.read_ue(), Ok(v));
+            assert_eq!(reader.read_rice(60), Ok(v));
+            assert_eq!(reader.read_rice(3), Ok(v & 0xff));
+        }
+        for v in signed {
+            assert_eq!(reader.read_se(), Ok(v));
+        }
+        assert_eq!(reader.read_unary(), Ok(0));
+        assert_eq!(reader.read_unary(), Ok(200));
+        assert_eq!(reader.read_rice(64), Ok(u64::MAX));
+        assert_eq!(reader.read_rice(100), Ok(7));
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn test_lsb0() {
+        // The code words are written bit by bit, so the first bit is bit 0 with `Lsb0`.
+        let mut writer: BitWriter<_, Lsb0, LittleEndian> = BitWriter::new([0u8; 1]);
+        writer.write_ue(3).unwrap();
+        writer.write_se(0).unwrap();
+        assert_eq!(writer.into_inner(), [0b0010_0100]);
+        let data = [0b0010_0100u8];
+        let mut reader: BitReader<_, Lsb0, LittleEndian> = BitSlice::from(data.as_ref()).into();
+        assert_eq!(reader.read_se(), Ok(2));
+        assert_eq!(reader.read_ue(), Ok(0));
+    }
+
+    #[test]
+    fn test_errors() {
+        // 65 leading zeros encode a code number that does not fit into 64 bits.
+        let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new([0u8; 17]);
+        writer.write_unary(65).unwrap();
+        writer.write(0u128, 65).unwrap();
+        let len = writer.position();
+        let data = writer.into_inner();
+        let mut reader =
+            BitReader::new(BitSlice::<_, Msb0, LittleEndian>::new(data.as_slice(), len));
+        assert_eq!(reader.read_ue(), Err(Error::ConversionError));
+        assert_eq!(reader.read_se(), Err(Error::ConversionError));
+        assert_eq!(reader.read_rice(63), Err(Error::ConversionError));
+        assert_eq!(reader.read_rice(0), Ok(65));
+        assert_eq!(reader.position(), 66);
+
+        // `u64::MAX + 1` is a valid code for `se(v)`, but not for `ue(v)`.
+        let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new([0u8; 17]);
+        writer.write_se(i64::MIN).unwrap();
+        let len = writer.position();
+        let data = writer.into_inner();
+        let mut reader =
+            BitReader::new(BitSlice::<_, Msb0, LittleEndian>::new(data.as_slice(), len));
+        assert_eq!(reader.read_ue(), Err(Error::ConversionError));
+        assert_eq!(reader.position(), 0);
+
+        let data = [0b0000_0010u8];
+        let mut reader: BitReader<_, Msb0, LittleEndian> = BitSlice::from(data.as_ref()).into();
+        assert_eq!(
+            reader.read_ue(),
+            Err(Error::EndOfData {
+                requested: 13,
+                remaining: 8
+            })
+        );
+        reader.skip(7).unwrap();
+        assert_eq!(
+            reader.read_unary(),
+            Err(Error::EndOfData {
+                requested: 2,
+                remaining: 1
+            })
+        );
+        assert_eq!(reader.position(), 7);
+
+        let mut writer: BitWriter<_> = BitWriter::new([0u8; 1]);
+        writer.write_unary(3).unwrap();
+        assert_eq!(
+            writer.write_ue(3),
+            Err(Error::EndOfData {
+                requested: 5,
+                remaining: 4
+            })
+        );
+        assert_eq!(writer.position(), 4);
+    }
+}
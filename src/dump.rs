@@ -0,0 +1,184 @@
+use alloc::string::String;
+use alloc::vec;
+use core::fmt::Write;
+use core::ops::Range;
+
+use crate::{BitOrder, BitSlice, BitStorage, ByteOrder, Error};
+
+/// Writes the marker of a field that covers the bit columns `first..=last`.
+fn write_marker(out: &mut String, indent: usize, first: usize, last: usize, name: &str) {
+    out.extend(core::iter::repeat_n(' ', indent + 2 * first));
+    if first == last {
+        out.push('^');
+    } else {
+        out.push('[');
+        out.extend(core::iter::repeat_n('-', 2 * (last - first) - 1));
+        out.push(']');
+    }
+    out.push(' ');
+    out.push_str(name);
+    out.push('\n');
+}
+
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> BitSlice<S, B, Endian> {
+    /// Renders the [BitSlice] as a table in the style of a hexdump, with named fields drawn
+    /// below the bits like in the register diagrams of a datasheet.
+    ///
+    /// Every byte of the storage that holds bits of the slice gets a row with its offset, its
+    /// value in hexadecimal, the indices of its first and last bit in the slice and its bits,
+    /// most significant bit on the left. The header numbers the bits within a byte according to
+    /// the bit order, `7 6 .. 0` for [`Lsb0`](crate::Lsb0) and `0 1 .. 7` for
+    /// [`Msb0`](crate::Msb0). Bits of the byte that are not part of the slice are shown as `.`.
+    ///
+    /// Below each row, every field of `fields` that has bits in the row is marked with
+    /// `[---]`, or `^` for a single bit, followed by its name. Fields are given as ranges of
+    /// bits of the slice and are drawn in the order of `fields`.
+    ///
+    /// # Returns
+    /// The table, or [`Error::RangeOutOfBounds`] if a field is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Lsb0};
+    /// let data = [0b1011_0010u8, 0b0111_0100];
+    /// let bits: BitSlice<_, Lsb0, LittleEndian> = data.as_ref().into();
+    /// let dump = bits.dump(&[("flag", 0..1), ("mode", 1..4), ("len", 4..12)]).unwrap();
+    /// assert_eq!(
+    ///     dump,
+    ///     "offset  hex  bits  7 6 5 4 3 2 1 0\n\
+    ///      000000  b2   7:0   1 0 1 1 0 0 1 0\n\
+    ///      \x20                                ^ flag\n\
+    ///      \x20                          [---] mode\n\
+    ///      \x20                  [-----] len\n\
+    ///      000001  74   15:8  0 1 1 1 0 1 0 0\n\
+    ///      \x20                          [-----] len\n"
+    /// );
+    /// ```
+    pub fn dump(&self, fields: &[(&str, Range<usize>)]) -> Result<String, Error> {
+        let len = self.len();
+        for (_, field) in fields {
+            if field.start > field.end || field.end > len {
+                return Err(Error::RangeOutOfBounds {
+                    start: field.start,
+                    end: field.end,
+                    len,
+                });
+            }
+        }
+        let bytes = self.storage();
+        let num_bits = bytes.len() * 8;
+        let find_bit = |n: usize| {
+            self.bit_order
+                .find_bit(self.layout(), self.range.start + n, num_bits)
+        };
+
+        // The bits of the slice occupy a contiguous run of bytes in either byte order, so its
+        // ends are found from its first and last bit.
+        let (first, last) = if len == 0 {
+            (1, 0)
+        } else {
+            let (a, b) = (find_bit(0).0, find_bit(len - 1).0);
+            (a.min(b), a.max(b))
+        };
+        // The index in the slice of every bit of those bytes.
+        let mut index = vec![None; (last + 1).saturating_sub(first) * 8];
+        for n in 0..len {
+            let (byte, bit) = find_bit(n);
+            index[(byte - first) * 8 + bit] = Some(n);
+        }
+        // The index of the bit in column `c`, which holds the bit of value `1 << (7 - c)`.
+        let column = |byte: usize, c: usize| index[(byte - first) * 8 + 7 - c];
+        let rows = (first..last + 1).map(|byte| {
+            let mut indices = (0..8).filter_map(|c| column(byte, c));
+            let label = match (indices.next(), indices.next_back()) {
+                (Some(a), Some(b)) => alloc::format!("{a}:{b}"),
+                (Some(a), None) => alloc::format!("{a}"),
+                _ => String::new(),
+            };
+            (byte, label)
+        });
+        let width = rows
+            .clone()
+            .map(|(_, label)| label.len())
+            .max()
+            .unwrap_or(0);
+        let width = width.max(4);
+        let indent = 8 + 5 + width + 2;
+
+        let mut out = String::new();
+        let _ = write!(out, "offset  hex  {:<width$}  ", "bits");
+        for c in 0..8 {
+            let position = if self.bit_order.msb_first() { c } else { 7 - c };
+            let separator = if c < 7 { " " } else { "\n" };
+            let _ = write!(out, "{position}{separator}");
+        }
+        for (byte, label) in rows {
+            let _ = write!(out, "{byte:06x}  {:02x}   {label:<width$}  ", bytes[byte]);
+            for c in 0..8 {
+                out.push(match column(byte, c) {
+                    Some(n) if self.get_bit(n) => '1',
+                    Some(_) => '0',
+                    None => '.',
+                });
+                out.push(if c < 7 { ' ' } else { '\n' });
+            }
+            for (name, field) in fields {
+                let mut columns =
+                    (0..8).filter(|&c| column(byte, c).is_some_and(|n| field.contains(&n)));
+                if let Some(a) = columns.next() {
+                    let b = columns.next_back().unwrap_or(a);
+                    write_marker(&mut out, indent, a, b, name);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigEndian, BitSlice, Error, LittleEndian, Lsb0, Msb0};
+
+    #[test]
+    fn test_msb0_big_endian() {
+        // A 16-bit big endian register whose bit 0 is its most significant bit.
+        let data = [0x81u8, 0x40];
+        let bits: BitSlice<_, Msb0, BigEndian> = data.as_ref().into();
+        let dump = bits.dump(&[("id", 0..3), ("ack", 9..10)]).unwrap();
+        assert_eq!(
+            dump,
+            "offset  hex  bits  0 1 2 3 4 5 6 7\n\
+             000000  81   8:15  1 0 0 0 0 0 0 1\n\
+             \x20                    ^ ack\n\
+             000001  40   0:7   0 1 0 0 0 0 0 0\n\
+             \x20                  [---] id\n"
+        );
+    }
+
+    #[test]
+    fn test_partial_bytes() {
+        let data = [0xffu8, 0x00, 0xff];
+        let bits: BitSlice<_, Lsb0, LittleEndian> = data.as_ref().into();
+        let dump = bits.slice(6..11).dump(&[("all", 0..5), ("empty", 2..2)]);
+        assert_eq!(
+            dump.unwrap(),
+            "offset  hex  bits  7 6 5 4 3 2 1 0\n\
+             000000  ff   1:0   1 1 . . . . . .\n\
+             \x20                  [-] all\n\
+             000001  00   4:2   . . . . . 0 0 0\n\
+             \x20                            [---] all\n"
+        );
+        assert_eq!(
+            bits.slice(..0).dump(&[]).unwrap(),
+            "offset  hex  bits  7 6 5 4 3 2 1 0\n"
+        );
+        assert_eq!(
+            bits.slice(..4).dump(&[("x", 2..5)]),
+            Err(Error::RangeOutOfBounds {
+                start: 2,
+                end: 5,
+                len: 4
+            })
+        );
+    }
+}
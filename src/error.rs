@@ -0,0 +1,122 @@
+use core::fmt;
+
+/// The error type of the fallible operations of this crate.
+///
+/// Errors about positions carry the offending index or range together with the length it was
+/// checked against, so they can be reported without access to the [`BitSlice`](crate::BitSlice).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// A value read from the bits does not fit into the requested type.
+    ConversionError,
+    /// A value does not fit into the bits it is written to.
+    Overflow,
+    /// A bit index is out of bounds of the slice.
+    IndexOutOfBounds {
+        /// The offending index.
+        index: usize,
+        /// The length of the slice.
+        len: usize,
+    },
+    /// A range of bits does not lie within the slice, or its start is greater than its end.
+    RangeOutOfBounds {
+        /// The start of the offending range.
+        start: usize,
+        /// The exclusive end of the offending range.
+        end: usize,
+        /// The length of the slice.
+        len: usize,
+    },
+    /// The storage has fewer bits than requested.
+    StorageTooSmall {
+        /// The requested number of bits.
+        bits: usize,
+        /// The number of bits in the storage.
+        capacity: usize,
+    },
+    /// The storage does not consist of whole words of its byte order, see
+    /// [`Words`](crate::Words).
+    PartialWord {
+        /// The number of bytes in the storage.
+        len: usize,
+        /// The number of bytes in a word.
+        word_bytes: usize,
+    },
+    /// A split point does not start a word of the storage.
+    Unaligned {
+        /// The offending index.
+        index: usize,
+        /// The number of bits in a word of the storage.
+        alignment: usize,
+    },
+    /// A read or write needs more bits than are left in the data or storage.
+    EndOfData {
+        /// The number of bits the read or write needs.
+        requested: usize,
+        /// The number of bits that are left.
+        remaining: usize,
+    },
+    /// Two slices that are combined bit by bit have different lengths.
+    LengthMismatch {
+        /// The length of the left operand.
+        left: usize,
+        /// The length of the right operand.
+        right: usize,
+    },
+    /// A string being parsed contains a character that is not a digit of its radix or a
+    /// separator.
+    InvalidDigit {
+        /// The byte offset of the character in the string.
+        index: usize,
+        /// The offending character.
+        character: char,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::ConversionError => f.write_str("value does not fit into the target type"),
+            Error::Overflow => f.write_str("value does not fit into the destination"),
+            Error::IndexOutOfBounds { index, len } => {
+                write!(f, "bit index {index} out of bounds for length {len}")
+            }
+            Error::RangeOutOfBounds { start, end, len } => {
+                write!(f, "bit range {start}..{end} out of bounds for length {len}")
+            }
+            Error::StorageTooSmall { bits, capacity } => {
+                write!(
+                    f,
+                    "{bits} bits do not fit into a storage of {capacity} bits"
+                )
+            }
+            Error::PartialWord { len, word_bytes } => {
+                write!(
+                    f,
+                    "a storage of {len} bytes does not consist of whole words of {word_bytes} bytes"
+                )
+            }
+            Error::Unaligned { index, alignment } => {
+                write!(
+                    f,
+                    "bit index {index} does not start a word of {alignment} bits"
+                )
+            }
+            Error::EndOfData {
+                requested,
+                remaining,
+            } => write!(
+                f,
+                "end of data: {requested} bits requested, but only {remaining} left"
+            ),
+            Error::LengthMismatch { left, right } => {
+                write!(f, "BitSlice lengths differ: {left} != {right}")
+            }
+            Error::InvalidDigit { index, character } => {
+                write!(f, "invalid digit {character:?} at offset {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
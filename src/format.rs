@@ -0,0 +1,208 @@
+use core::fmt;
+
+use crate::{BitOrder, BitSlice, BitStorage, ByteOrder};
+
+/// Writes `n` copies of `fill`.
+fn write_fill(f: &mut fmt::Formatter<'_>, fill: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+/// Writes the bits of `slice` as digits of `width` bits each, honouring the width, fill,
+/// alignment and `0` flag of `f`.
+///
+/// The slice is treated as a number whose most significant bit is its first bit, so the first
+/// digit is padded with leading zeros if the length is not a multiple of `width`. Every `group`
+/// digits, counted from the last one, are separated by `separator`.
+fn write_digits<S: BitStorage, B: BitOrder, Endian: ByteOrder>(
+    slice: &BitSlice<S, B, Endian>,
+    f: &mut fmt::Formatter<'_>,
+    width: usize,
+    prefix: &str,
+    digits: &[u8; 16],
+    group: Option<(usize, char)>,
+    default_align: fmt::Alignment,
+) -> fmt::Result {
+    let len = slice.len();
+    let count = len.div_ceil(width);
+    let pad_bits = count * width - len;
+    let separators = match group {
+        Some((size, _)) if size > 0 && count > 0 => (count - 1) / size,
+        _ => 0,
+    };
+    let prefix = if f.alternate() { prefix } else { "" };
+    let total = prefix.len() + count + separators;
+    let padding = f.width().unwrap_or(0).saturating_sub(total);
+
+    let (before, after) = if f.sign_aware_zero_pad() {
+        (0, 0)
+    } else {
+        match f.align().unwrap_or(default_align) {
+            fmt::Alignment::Left => (0, padding),
+            fmt::Alignment::Right => (padding, 0),
+            fmt::Alignment::Center => (padding / 2, padding - padding / 2),
+        }
+    };
+    write_fill(f, f.fill(), before)?;
+    f.write_str(prefix)?;
+    if f.sign_aware_zero_pad() {
+        write_fill(f, '0', padding)?;
+    }
+    for i in 0..count {
+        if let Some((size, separator)) = group {
+            if size > 0 && i > 0 && (count - i).is_multiple_of(size) {
+                fmt::Write::write_char(f, separator)?;
+            }
+        }
+        let mut digit = 0;
+        for k in 0..width {
+            let n = (i * width + k).wrapping_sub(pad_bits);
+            digit = digit << 1 | (n < len && slice.get_bit(n)) as usize;
+        }
+        fmt::Write::write_char(f, digits[digit] as char)?;
+    }
+    write_fill(f, f.fill(), after)
+}
+
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// A [BitSlice] whose digits are separated into groups when it is formatted.
+///
+/// It is returned by [`BitSlice::grouped`] and supports the same formatting traits as the
+/// [BitSlice].
+pub struct Grouped<'a, S, B, Endian> {
+    slice: &'a BitSlice<S, B, Endian>,
+    size: usize,
+    separator: char,
+}
+
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> BitSlice<S, B, Endian> {
+    /// Returns a wrapper that formats the [BitSlice] with `separator` between every `size`
+    /// digits, counted from the last digit like in integer literals.
+    ///
+    /// Like the [BitSlice] itself, the wrapper writes the bits in the order of the slice, with
+    /// the first bit as the most significant bit of the first digit, whatever the bit and byte
+    /// order. For a [`Lsb0`](crate::Lsb0) slice over `[0x2e]`, `{:x}` therefore writes `74`,
+    /// while [`BitSlice::get_field`] returns `0x2e`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Msb0};
+    /// let data = [0b1011_0010u8, 0b0111_0100];
+    /// let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+    /// assert_eq!(format!("{:#x}", bits.grouped(2, '_')), "0xb2_74");
+    /// assert_eq!(format!("{}", bits.slice(..10).grouped(4, ' ')), "10 1100 1001");
+    /// ```
+    #[inline(always)]
+    pub fn grouped(&self, size: usize, separator: char) -> Grouped<'_, S, B, Endian> {
+        Grouped {
+            slice: self,
+            size,
+            separator,
+        }
+    }
+}
+
+macro_rules! impl_fmt {
+    ($trait:ident, $width:expr, $prefix:expr, $digits:expr, $align:expr) => {
+        /// Writes the bits in the order of the slice, so its first bit is the most significant
+        /// bit of the first digit, whatever the bit and byte order. The digits can therefore
+        /// differ from the value returned by [`BitSlice::get_field`], which follows the bit and
+        /// byte order.
+        impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> fmt::$trait for BitSlice<S, B, Endian> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_digits(self, f, $width, $prefix, $digits, None, $align)
+            }
+        }
+        impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> fmt::$trait
+            for Grouped<'_, S, B, Endian>
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let group = Some((self.size, self.separator));
+                write_digits(self.slice, f, $width, $prefix, $digits, group, $align)
+            }
+        }
+    };
+}
+impl_fmt!(Display, 1, "", LOWER, fmt::Alignment::Left);
+impl_fmt!(Binary, 1, "0b", LOWER, fmt::Alignment::Right);
+impl_fmt!(Octal, 3, "0o", LOWER, fmt::Alignment::Right);
+impl_fmt!(LowerHex, 4, "0x", LOWER, fmt::Alignment::Right);
+impl_fmt!(UpperHex, 4, "0x", UPPER, fmt::Alignment::Right);
+
+impl<S, B, Endian> fmt::Debug for BitSlice<S, B, Endian>
+where
+    S: BitStorage,
+    B: BitOrder + fmt::Debug,
+    Endian: ByteOrder + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BitSlice")
+            .field("bit_order", &self.bit_order)
+            .field("byte_order", &self.byte_order)
+            .field("bytes", &self.bytes.as_words())
+            .field("range", &self.range)
+            .field("bits", &format_args!("{self}"))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigEndian, BitSlice, LittleEndian, Lsb0, Msb0};
+    extern crate alloc;
+    extern crate std;
+    use alloc::format;
+
+    #[test]
+    fn test_radix() {
+        let data = [0b1011_0010u8, 0b0111_0100];
+        let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+        assert_eq!(format!("{bits}"), "1011001001110100");
+        assert_eq!(format!("{bits:b}"), "1011001001110100");
+        assert_eq!(format!("{bits:x}"), "b274");
+        assert_eq!(format!("{bits:X}"), "B274");
+        assert_eq!(format!("{bits:o}"), "131164");
+        assert_eq!(format!("{:o}", bits.slice(..6)), "54");
+        // The first digit is padded with leading zeros.
+        assert_eq!(format!("{:x}", bits.slice(..10)), "2c9");
+        assert_eq!(format!("{:x}", bits.slice(..0)), "");
+
+        // The digits do not depend on the bit and byte order.
+        let data = [0x2eu8, 0x4d];
+        let other: BitSlice<_, Lsb0, BigEndian> = data.as_ref().into();
+        assert_eq!(format!("{other:x}"), "b274");
+        assert_eq!(format!("{:x}", other.slice(8..)), "74");
+        assert_eq!(other.get_field::<u8>(8..), Ok(0x2e));
+    }
+
+    #[test]
+    fn test_flags() {
+        let bits: BitSlice<_, Msb0, LittleEndian> = [0xa5u8].as_ref().into();
+        assert_eq!(format!("{bits:#x}"), "0xa5");
+        assert_eq!(format!("{bits:#b}"), "0b10100101");
+        assert_eq!(format!("{bits:#o}"), "0o245");
+        assert_eq!(format!("{bits:#}"), "10100101");
+        assert_eq!(format!("{bits:6x}"), "    a5");
+        assert_eq!(format!("{bits:<6x}|"), "a5    |");
+        assert_eq!(format!("{bits:*^7X}"), "**A5***");
+        assert_eq!(format!("{bits:#06x}"), "0x00a5");
+        assert_eq!(format!("{bits:10}|"), "10100101  |");
+        assert_eq!(format!("{:#x}", bits.grouped(1, '_')), "0xa_5");
+        assert_eq!(format!("{:>12b}", bits.grouped(3, ' ')), "  10 100 101");
+        assert_eq!(format!("{:x}", bits.grouped(0, '_')), "a5");
+    }
+
+    #[test]
+    fn test_debug() {
+        let bits: BitSlice<_, Lsb0, LittleEndian> = [0x05u8].as_ref().into();
+        assert_eq!(
+            format!("{:?}", bits.slice(1..4)),
+            "BitSlice { bit_order: Lsb0, byte_order: LittleEndian, bytes: [5], \
+             range: 1..4, bits: 010 }"
+        );
+    }
+}
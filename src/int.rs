@@ -0,0 +1,69 @@
+mod private {
+    pub trait Sealed {}
+}
+
+/// The `Unsigned` trait is implemented by all unsigned integer types that can be read from and
+/// written to a range of bits of a [`BitSlice`](crate::BitSlice).
+pub trait Unsigned: Copy + private::Sealed {
+    /// The number of bits of the type.
+    const BITS: usize;
+    /// Converts a `u128` to this type, discarding any bits that do not fit.
+    #[doc(hidden)]
+    fn from_u128(value: u128) -> Self;
+    /// Converts the value to a `u128`.
+    #[doc(hidden)]
+    fn to_u128(self) -> u128;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Unsigned for $t {
+                const BITS: usize = <$t>::BITS as usize;
+                #[inline(always)]
+                fn from_u128(value: u128) -> Self {
+                    value as $t
+                }
+                #[inline(always)]
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// The `Signed` trait is implemented by all signed integer types that can be read from and
+/// written to a range of bits of a [`BitSlice`](crate::BitSlice) in two's complement.
+pub trait Signed: Copy + private::Sealed {
+    /// The number of bits of the type.
+    const BITS: usize;
+    /// Converts an `i128` to this type, returning `None` if it does not fit.
+    #[doc(hidden)]
+    fn from_i128(value: i128) -> Option<Self>;
+    /// Converts the value to an `i128`.
+    #[doc(hidden)]
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Signed for $t {
+                const BITS: usize = <$t>::BITS as usize;
+                #[inline(always)]
+                fn from_i128(value: i128) -> Option<Self> {
+                    value.try_into().ok()
+                }
+                #[inline(always)]
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+impl_signed!(i8, i16, i32, i64, i128, isize);
@@ -6,15 +6,36 @@
 //!
 //! - **Bit Order Handling**: Support for different bit ordering (e.g., MSB-first, LSB-first), allowing users to specify how bits are read from and written to the underlying storage.
 //! - **Byte Order Handling**: Support for different byte endianness (e.g., little endian, big endian), enabling interpretation of byte sequences according to the specified byte order.
+//! - **Word Storage**: The storage of a [`BitSlice`] is a sequence of `u8`, `u16`, `u32` or `u64` words, such as `[u32; N]` memory-mapped registers or a `&mut [u64]` bitmap, and [`Lsb0`] and [`Msb0`] number the bits within each word, see [`BitStorage`]. The [`Words`] byte order gives byte storage the same layout, and [`BitSlice::from_words`] creates a [`BitSlice`] over a slice of words.
+//! - **[`VolatileBits`] Registers**: Accesses the bits of memory-mapped registers with volatile reads and writes, updating fields with a read-modify-write of the words holding them.
 //! - **[`BitSlice`] Structure**: The primary feature of this crate, [`BitSlice`] provides a view into a sequence of bits, supporting operations like reading a bit at a specific index, slicing a range of bits, and setting the value of a bit. [`BitSlice`] is flexible in terms of the underlying storage and can be parameterized with different bit and byte orders.
-//! - **[`BitIter`] Iterator**: An iterator over the bits in a [`BitSlice`], offering both read and write capabilities for individual bits.
-//! - **Macros for Convenience**: Macros like [`bits!`] to facilitate easy and concise creation of [`BitSlice`] instances from literal sequences of bits.
+//! - **Non-panicking API**: Every panicking method and operator has a fallible counterpart, e.g. [`BitSlice::get`] for [`BitSlice::get_bit`], [`BitSlice::try_slice`] for [`BitSlice::slice`] and [`BitSlice::try_and_assign`] for `&=`, returning an [`Error`] that carries the offending index and length. The `const` constructors are the exception, as they turn invalid arguments into compile errors instead.
+//! - **Integer Fields**: [`BitSlice::get_field`] and [`BitSlice::set_field`] read and write unsigned integers of any width at any bit position, with signed counterparts such as [`BitSlice::get_signed_field`]. The bit order decides which bit of a field is the most significant one.
+//! - **Bitwise Operators**: `&`, `|`, `^`, `!` and their assigning variants combine [`BitSlice`]s with mutable storage in place, and [`BitSlice::shift_left`], [`BitSlice::rotate_left`] and friends (also available as `<<` and `>>`) shift and rotate them.
+//! - **Counting and Searching**: [`BitSlice::count_ones`], [`BitSlice::first_one`], [`BitSlice::leading_zeros`] and friends inspect a whole word of bits at a time.
+//! - **[`BitReader`] Stream Reader**: A cursor that reads bits, integers and sub-slices from a [`BitSlice`] one after another, reporting the end of the data instead of panicking.
+//! - **[`BitWriter`] Stream Writer**: The counterpart of [`BitReader`], which writes bits, integers and [`BitSlice`]s one after another into a byte slice, with padding to byte boundaries.
+//! - **Variable-Length Codes**: [`BitReader::read_ue`], [`BitReader::read_se`], [`BitReader::read_rice`] and [`BitReader::read_unary`] decode the Exp-Golomb, Golomb-Rice and unary codes of video and audio bitstreams, and [`BitWriter`] has matching methods to encode them.
+//! - **Varints**: [`BitReader::read_uleb128`], [`BitReader::read_sleb128`] and [`BitReader::read_zigzag`] decode LEB128 and protobuf varints at any bit position, and [`BitWriter`] has matching methods to encode them.
+//! - **[`BitArray`] Structure**: A fixed-size array of bits with a compile-time length that can be built in a `const` or `static` and dereferences to a read-only [`BitSlice`].
+//! - **[`BitIter`] and [`BitIterMut`] Iterators**: Double-ended iterators over the bits in a [`BitSlice`], the latter yielding writable [`BitMut`] references to individual bits.
+//! - **[`BitIndices`] and [`BitRuns`] Iterators**: Iterators over the indices of set or unset bits and over runs of equal bits, which skip whole words at a time.
+//! - **[`BitPack`] Trait**: Packs registers and packet headers into a [`BitSlice`] and unpacks them again.
+//! - **Formatting**: [`BitSlice`] implements [`Display`](core::fmt::Display), [`Binary`](core::fmt::Binary), [`Octal`](core::fmt::Octal), [`LowerHex`](core::fmt::LowerHex), [`UpperHex`](core::fmt::UpperHex) and [`Debug`](core::fmt::Debug) without allocating, with support for width, `#` prefixes and, through [`BitSlice::grouped`], digit separators.
+//! - **Macros for Convenience**: Macros like [`bits!`] to facilitate easy and concise creation of [`BitSlice`] instances from literal sequences of bits, strings such as `"1011_0010"` or integers, even in `const` and `static` items, and [`bitfield!`] to declare register types with typed getters and setters without proc-macros.
 //!
 //! ### Optional `alloc` Feature
 //!
 //! Enabling the `alloc` feature adds:
+//! - `BitVec`, an owned and growable sequence of bits backed by a `Vec<u8>`.
+//! - [`BitWriter`] support for `Vec<u8>`, which grows as bits are written.
 //! - Conversion of [`BitSlice`] to a bit string (e.g., "1010110").
-//! - Implementation of the [`Debug`](core::fmt::Debug) trait for [`BitSlice`].
+//! - Parsing of `BitVec` from binary, octal and hexadecimal strings (e.g., "0xb2"), which is the inverse of the conversion above.
+//! - `BitSlice::dump`, which renders a [`BitSlice`] as a hexdump-style table with named fields drawn below its bits.
+//!
+//! ### Optional `derive` Feature
+//!
+//! Enabling the `derive` feature adds `#[derive(BitPack)]` for structs with fields at fixed bit ranges and for fieldless enums, see [`BitPack`].
 //!
 //! ## Example
 //!
@@ -40,32 +61,59 @@
 //!
 //! ## Note
 //!
-//! Although [`BitSlice`] an be created from `[u8; N]` this is not recommended. This will create method instanced for each `N` and therefore lead to a lot of bloat.
+//! Although [`BitSlice`] an be created from `[u8; N]` or other arrays this is not recommended. This will create method instanced for each `N` and therefore lead to a lot of bloat.
 //! When possible use `.as_ref().into()` or `.as_mut().into()`.
 
 #![no_std]
 
-use core::ops::{Bound, Range, RangeBounds};
+use core::cell::Cell;
+use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod array;
+mod codes;
+#[cfg(feature = "alloc")]
+mod dump;
+mod error;
+mod format;
+mod int;
+mod ops;
 mod order;
+mod pack;
+mod reader;
+mod search;
+mod shared;
+mod storage;
+mod varint;
+#[cfg(feature = "alloc")]
+mod vec;
+mod volatile;
+mod writer;
+pub use array::*;
+#[cfg(feature = "derive")]
+pub use bitslicer_derive::BitPack;
+pub use error::*;
+pub use format::*;
+pub use int::*;
 pub use order::*;
-
-#[doc(hidden)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum Error {
-    ConversionError,
-    Overflow,
-}
+pub use pack::*;
+pub use reader::*;
+pub use shared::*;
+pub use storage::*;
+#[cfg(feature = "alloc")]
+pub use vec::*;
+pub use volatile::*;
+pub use writer::*;
 
 /// Represents a view into a sequence of bits.
 ///
 /// This struct can handle different bit orders and byte endianness, making it flexible for various use cases.
 ///
 /// # Type Parameters
-/// - `S`: The underlying storage type, typically a byte slice.
+/// - `S`: The underlying storage type, typically a slice or an array of bytes or wider words, see
+///   [`BitStorage`].
 /// - `B`: The bit order, which dictates the order in which bits are read.
 /// - `Endian`: The byte order, which dictates the order in which bytes are read.
 pub struct BitSlice<S, B = Lsb0, Endian = LittleEndian> {
@@ -78,7 +126,7 @@ pub struct BitSlice<S, B = Lsb0, Endian = LittleEndian> {
 impl<'a, B, Endian> From<&'a [u8]> for BitSlice<&'a [u8], B, Endian>
 where
     B: Default,
-    Endian: Default,
+    Endian: ByteOrder + Default,
 {
     #[inline(always)]
     fn from(bytes: &'a [u8]) -> Self {
@@ -89,7 +137,7 @@ where
 impl<'a, B, Endian> From<&'a mut [u8]> for BitSlice<&'a mut [u8], B, Endian>
 where
     B: Default,
-    Endian: Default,
+    Endian: ByteOrder + Default,
 {
     #[inline(always)]
     fn from(bytes: &'a mut [u8]) -> Self {
@@ -97,15 +145,292 @@ where
         Self::new(bytes, len)
     }
 }
-impl<S: AsRef<[u8]>, B, Endian> BitSlice<S, B, Endian> {}
+impl<'a, W: BitStore, B: Default, Endian: ByteOrder + Default> BitSlice<&'a [W], B, Endian> {
+    /// Creates a new [BitSlice] over all bits of a slice of words, see [`BitStorage`].
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Lsb0};
+    /// let bitmap = [0x8000_0000_0000_0001u64, 0x2];
+    /// let bits: BitSlice<_, Lsb0, LittleEndian> = BitSlice::from_words(&bitmap);
+    /// assert!(bits.iter_ones().eq([0, 63, 65]));
+    /// ```
+    pub fn from_words(words: &'a [W]) -> Self {
+        Self::new(words, words.len() * W::BITS)
+    }
+}
+impl<'a, W: BitStore, B: Default, Endian: ByteOrder + Default> BitSlice<&'a mut [W], B, Endian> {
+    /// Creates a new mutable [BitSlice] over all bits of a slice of words, see [`BitStorage`].
+    pub fn from_words_mut(words: &'a mut [W]) -> Self {
+        let len = words.len() * W::BITS;
+        Self::new(words, len)
+    }
+}
+impl<const N: usize, B, Endian> BitSlice<[u8; N], B, Endian> {
+    /// Creates a new [BitSlice] over an array with specific bit and byte order in a `const`
+    /// context.
+    ///
+    /// See [`BitSlice::new_with_order`].
+    ///
+    /// # Panics
+    /// Panics if `bytes` holds fewer than `num_bits` bits or does not consist of whole words.
+    #[inline(always)]
+    #[track_caller]
+    pub const fn from_array_with_order(
+        bytes: [u8; N],
+        num_bits: usize,
+        bit_order: B,
+        endianness: Endian,
+    ) -> Self
+    where
+        Endian: ByteOrder,
+    {
+        assert!(
+            N.is_multiple_of(Endian::WORD_BYTES),
+            "the storage does not consist of whole words"
+        );
+        assert!(
+            num_bits <= N * 8,
+            "the storage holds fewer bits than requested"
+        );
+        Self {
+            bytes,
+            range: 0..num_bits,
+            bit_order,
+            byte_order: endianness,
+        }
+    }
+}
+impl<'a, B, Endian> BitSlice<&'a [u8], B, Endian> {
+    /// Creates a new [BitSlice] over a byte slice with specific bit and byte order in a `const`
+    /// context.
+    ///
+    /// See [`BitSlice::new_with_order`].
+    ///
+    /// # Panics
+    /// Panics if `bytes` holds fewer than `num_bits` bits or does not consist of whole words.
+    #[inline(always)]
+    #[track_caller]
+    pub const fn from_slice_with_order(
+        bytes: &'a [u8],
+        num_bits: usize,
+        bit_order: B,
+        endianness: Endian,
+    ) -> Self
+    where
+        Endian: ByteOrder,
+    {
+        assert!(
+            bytes.len().is_multiple_of(Endian::WORD_BYTES),
+            "the storage does not consist of whole words"
+        );
+        assert!(
+            num_bits <= bytes.len() * 8,
+            "the storage holds fewer bits than requested"
+        );
+        Self {
+            bytes,
+            range: 0..num_bits,
+            bit_order,
+            byte_order: endianness,
+        }
+    }
+}
+/// Constructors that can be used in `const` and `static` items, such as lookup tables and sync
+/// words that need no initialization at runtime.
+impl<const N: usize, B: ConstBitOrder, Endian: ConstByteOrder> BitSlice<[u8; N], B, Endian> {
+    /// Creates a new [BitSlice] over an array in a `const` context.
+    ///
+    /// See [`BitSlice::new`].
+    ///
+    /// # Panics
+    /// Panics if `bytes` holds fewer than `num_bits` bits.
+    #[inline(always)]
+    #[track_caller]
+    pub const fn from_array(bytes: [u8; N], num_bits: usize) -> Self {
+        Self::from_array_with_order(bytes, num_bits, B::ORDER, Endian::ORDER)
+    }
+    /// Creates a new [BitSlice] whose bits are `bits`.
+    ///
+    /// # Panics
+    /// Panics if the array holds fewer bits than `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Msb0};
+    /// const SYNC: BitSlice<[u8; 1], Msb0, LittleEndian> =
+    ///     BitSlice::from_bools(&[true, false, true, true]);
+    /// assert_eq!(SYNC.len(), 4);
+    /// assert_eq!(SYNC.get_field::<u8>(..), Ok(0b1011));
+    /// ```
+    #[track_caller]
+    pub const fn from_bools(bits: &[bool]) -> Self {
+        let mut slice = Self::from_array([0; N], bits.len());
+        let mut i = 0;
+        while i < bits.len() {
+            slice.const_set_bit(i, bits[i]);
+            i += 1;
+        }
+        slice
+    }
+    /// Creates a new [BitSlice] of `num_bits` bits that holds `value` as an unsigned integer.
+    ///
+    /// The value is laid out like [`BitSlice::set_field`] writes it, so the bit order decides
+    /// whether the first bit is the most or the least significant one.
+    ///
+    /// # Panics
+    /// Panics if the array holds fewer than `num_bits` bits or `value` does not fit into
+    /// `num_bits` bits.
+    #[track_caller]
+    pub const fn from_uint(value: u128, num_bits: usize) -> Self {
+        assert!(
+            num_bits >= 128 || value >> num_bits == 0,
+            "value does not fit into the bits"
+        );
+        let mut slice = Self::from_array([0; N], num_bits);
+        let mut i = 0;
+        while i < num_bits && i < 128 {
+            let n = if B::MSB_FIRST { num_bits - 1 - i } else { i };
+            slice.const_set_bit(n, value >> i & 1 != 0);
+            i += 1;
+        }
+        slice
+    }
+    /// Creates a new [BitSlice] from a string literal passed to [`bits!`].
+    #[doc(hidden)]
+    #[track_caller]
+    pub const fn from_literal(literal: &str) -> Self {
+        let literal = literal.as_bytes();
+        let mut slice = Self::from_array([0; N], count_literal_bits(literal));
+        let (mut i, mut n) = (0, 0);
+        while i < literal.len() {
+            if literal[i] == b'0' || literal[i] == b'1' {
+                slice.const_set_bit(n, literal[i] == b'1');
+                n += 1;
+            }
+            i += 1;
+        }
+        slice
+    }
+    /// Sets bit `n` in a `const` context.
+    #[inline(always)]
+    const fn const_set_bit(&mut self, n: usize, value: bool) {
+        let (byte, bit) = const_find_bit::<B, Endian>(self.range.start + n, N);
+        if value {
+            self.bytes[byte] |= 1 << bit;
+        } else {
+            self.bytes[byte] &= !(1 << bit);
+        }
+    }
+}
+
+/// Returns the number of bits of a string literal such as `"1011_0010"`, which may separate
+/// the bits with underscores and whitespace.
+#[track_caller]
+const fn count_literal_bits(literal: &[u8]) -> usize {
+    let (mut i, mut n) = (0, 0);
+    while i < literal.len() {
+        match literal[i] {
+            b'0' | b'1' => n += 1,
+            b'_' | b' ' | b'\t' | b'\n' | b'\r' => {}
+            _ => panic!("bit literals may only contain 0, 1, underscores and whitespace"),
+        }
+        i += 1;
+    }
+    n
+}
+
+/// A single literal passed to [`bits!`]. String literals are parsed in a `const` context, while
+/// integer and `bool` literals become a single bit at runtime like in the list form.
+#[doc(hidden)]
+pub struct __Literal<T>(pub T);
+
+impl __Literal<&str> {
+    pub const fn num_bits(&self) -> usize {
+        count_literal_bits(self.0.as_bytes())
+    }
+    #[track_caller]
+    pub const fn bits<const N: usize, B: ConstBitOrder, Endian: ConstByteOrder>(
+        self,
+    ) -> BitSlice<[u8; N], B, Endian> {
+        BitSlice::from_literal(self.0)
+    }
+}
+
+impl<T: __LiteralBit> __Literal<T> {
+    pub const fn num_bits(&self) -> usize {
+        1
+    }
+    pub fn bits<const N: usize, B: BitOrder + Default, Endian: ByteOrder + Default>(
+        self,
+    ) -> BitSlice<[u8; N], B, Endian> {
+        let mut slice = BitSlice::new([0; N], 1);
+        slice.set_bit(0, self.0.to_bit());
+        slice
+    }
+}
+
+/// The literals that [`bits!`] accepts as a single bit, like the elements of the list form.
+#[doc(hidden)]
+pub trait __LiteralBit {
+    fn to_bit(self) -> bool;
+}
+
+macro_rules! impl_literal_bit {
+    ($($ty:ty),*) => {
+        $(
+            impl __LiteralBit for $ty {
+                fn to_bit(self) -> bool {
+                    self as usize != 0
+                }
+            }
+        )*
+    };
+}
+impl_literal_bit!(
+    bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+impl<S: BitStorage, B, Endian> BitSlice<S, B, Endian> {}
 impl<S, B, Endian> BitSlice<S, B, Endian> {
     /// Returns the number of bits in the slice.
     #[inline(always)]
     pub const fn len(&self) -> usize {
         self.range.end - self.range.start
     }
+    /// Returns `true` if the slice contains no bits.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.range.end == self.range.start
+    }
+    /// Splits the field `start..end` into the position of its `n` least significant bits and
+    /// the range of the remaining, more significant bits.
+    ///
+    /// If `msb_first` is set the first bit of the field is its most significant bit, otherwise
+    /// it is the least significant bit.
+    fn split_field(
+        &self,
+        start: usize,
+        end: usize,
+        n: usize,
+        msb_first: bool,
+    ) -> (usize, Range<usize>) {
+        if msb_first {
+            (end - n, start..end - n)
+        } else {
+            (start, start + n..end)
+        }
+    }
+    /// Converts `range` to start and exclusive end indices within the slice.
+    fn try_bit_range(&self, range: impl RangeBounds<usize>) -> Result<(usize, usize), Error> {
+        range_to_bounds(
+            range.start_bound().cloned(),
+            range.end_bound().cloned(),
+            self.len(),
+        )
+    }
 }
-impl<S: AsRef<[u8]>, B, Endian> BitSlice<S, B, Endian> {
+impl<S: BitStorage, B, Endian> BitSlice<S, B, Endian> {
     /// Creates a new [BitSlice] from a given storage with default bit and byte order.
     ///
     /// # Arguments
@@ -118,7 +443,7 @@ impl<S: AsRef<[u8]>, B, Endian> BitSlice<S, B, Endian> {
     pub fn new(bytes: S, num_bits: usize) -> Self
     where
         B: Default,
-        Endian: Default,
+        Endian: ByteOrder + Default,
     {
         Self::new_with_order(bytes, num_bits, Default::default(), Default::default())
     }
@@ -132,15 +457,78 @@ impl<S: AsRef<[u8]>, B, Endian> BitSlice<S, B, Endian> {
     ///
     /// # Returns
     /// A new [BitSlice] instance with the specified ordering.
+    ///
+    /// # Panics
+    /// Panics if `bytes` holds fewer than `num_bits` bits or does not consist of whole words of
+    /// the byte order, see [`Words`]. See [`BitSlice::try_new_with_order`] for a non-panicking
+    /// version.
     #[inline(always)]
-    pub fn new_with_order(bytes: S, num_bits: usize, bit_order: B, endianness: Endian) -> Self {
-        assert!(bytes.as_ref().len() * 8 >= num_bits);
-        Self {
+    #[track_caller]
+    pub fn new_with_order(bytes: S, num_bits: usize, bit_order: B, endianness: Endian) -> Self
+    where
+        Endian: ByteOrder,
+    {
+        Self::try_new_with_order(bytes, num_bits, bit_order, endianness)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Creates a new [BitSlice] from a given storage with default bit and byte order, or returns
+    /// [`Error::StorageTooSmall`] if `bytes` holds fewer than `num_bits` bits or
+    /// [`Error::PartialWord`] if it does not consist of whole words.
+    ///
+    /// See [`BitSlice::new`].
+    #[inline(always)]
+    pub fn try_new(bytes: S, num_bits: usize) -> Result<Self, Error>
+    where
+        B: Default,
+        Endian: ByteOrder + Default,
+    {
+        Self::try_new_with_order(bytes, num_bits, Default::default(), Default::default())
+    }
+    /// Creates a new [BitSlice] with specific bit and byte order, or returns
+    /// [`Error::StorageTooSmall`] if `bytes` holds fewer than `num_bits` bits or
+    /// [`Error::PartialWord`] if it does not consist of whole words.
+    ///
+    /// See [`BitSlice::new_with_order`].
+    pub fn try_new_with_order(
+        bytes: S,
+        num_bits: usize,
+        bit_order: B,
+        endianness: Endian,
+    ) -> Result<Self, Error>
+    where
+        Endian: ByteOrder,
+    {
+        let len = core::mem::size_of_val(bytes.as_words());
+        let word_bytes = Layout::<Endian, S::Word>::WORD_BYTES;
+        if !len.is_multiple_of(word_bytes) {
+            return Err(Error::PartialWord { len, word_bytes });
+        }
+        let capacity = len * 8;
+        if capacity < num_bits {
+            return Err(Error::StorageTooSmall {
+                bits: num_bits,
+                capacity,
+            });
+        }
+        Ok(Self {
             bytes,
             range: 0..num_bits,
             bit_order,
             byte_order: endianness,
-        }
+        })
+    }
+    /// Returns the memory of the storage as bytes.
+    #[inline(always)]
+    pub(crate) fn storage(&self) -> &[u8] {
+        words_as_bytes(self.bytes.as_words())
+    }
+    /// Returns the byte order with the words of the storage, by which the bits are located.
+    #[inline(always)]
+    pub(crate) fn layout(&self) -> Layout<Endian, S::Word>
+    where
+        Endian: ByteOrder,
+    {
+        Layout::new(self.byte_order)
     }
     /// Retrieves the value of a bit at a specified index.
     ///
@@ -151,19 +539,43 @@ impl<S: AsRef<[u8]>, B, Endian> BitSlice<S, B, Endian> {
     /// `true` if the bit is set; `false` otherwise.
     ///
     /// # Panics
-    /// Panics if `n` is out of bounds.
+    /// Panics if `n` is out of bounds. See [`BitSlice::get`] for a non-panicking version.
+    #[track_caller]
     pub fn get_bit(&self, n: usize) -> bool
     where
         B: BitOrder,
         Endian: ByteOrder,
     {
-        assert!(n < self.range.len());
-        let (byte, bit) = self.bit_order.find_bit(
-            self.byte_order,
-            self.range.start + n,
-            self.bytes.as_ref().len() * 8,
+        assert!(
+            n < self.len(),
+            "{}",
+            Error::IndexOutOfBounds {
+                index: n,
+                len: self.len()
+            }
         );
-        (self.bytes.as_ref()[byte] & (1 << bit)) > 0
+        let bytes = self.storage();
+        let (byte, bit) =
+            self.bit_order
+                .find_bit(self.layout(), self.range.start + n, bytes.len() * 8);
+        (bytes[byte] & (1 << bit)) > 0
+    }
+    /// Retrieves the value of a bit at a specified index, or `None` if `n` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, bits};
+    /// let bits: BitSlice<_> = bits![1, 0];
+    /// assert_eq!(bits.get(0), Some(true));
+    /// assert_eq!(bits.get(2), None);
+    /// ```
+    #[inline(always)]
+    pub fn get(&self, n: usize) -> Option<bool>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        (n < self.len()).then(|| self.get_bit(n))
     }
     /// Returns a [BitSlice] representing a sub-slice of the current slice.
     ///
@@ -172,117 +584,915 @@ impl<S: AsRef<[u8]>, B, Endian> BitSlice<S, B, Endian> {
     ///
     /// # Returns
     /// A new [BitSlice] representing the specified range.
-    pub fn slice<'a>(&'a self, range: impl RangeBounds<usize>) -> BitSlice<&[u8], B, Endian>
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds. See [`BitSlice::try_slice`] for a non-panicking
+    /// version.
+    #[track_caller]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> BitSlice<&[S::Word], B, Endian>
     where
         B: Copy,
         Endian: Copy,
     {
-        let (start_bit, end_excl_bit) = range_to_bounds(
-            range.start_bound().cloned(),
-            range.end_bound().cloned(),
-            self.range.len(),
-        );
-        assert!(start_bit <= end_excl_bit);
-        assert!(end_excl_bit <= self.range.len());
-        BitSlice {
-            bytes: self.bytes.as_ref(),
+        self.try_slice(range).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Returns a [BitSlice] representing a sub-slice of the current slice, or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds.
+    ///
+    /// See [`BitSlice::slice`].
+    pub fn try_slice(
+        &self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<BitSlice<&[S::Word], B, Endian>, Error>
+    where
+        B: Copy,
+        Endian: Copy,
+    {
+        let (start_bit, end_excl_bit) = self.try_bit_range(range)?;
+        Ok(BitSlice {
+            bytes: self.bytes.as_words(),
             range: (self.range.start + start_bit)..(self.range.start + end_excl_bit),
             bit_order: self.bit_order,
             byte_order: self.byte_order,
+        })
+    }
+    /// Divides the slice into two at an index.
+    ///
+    /// # Arguments
+    /// * `mid` - The index of the first bit of the second slice.
+    ///
+    /// # Returns
+    /// A [BitSlice] over the bits `..mid` and one over the bits `mid..`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len()`. See [`BitSlice::try_split_at`] for a non-panicking version.
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn split_at(
+        &self,
+        mid: usize,
+    ) -> (
+        BitSlice<&[S::Word], B, Endian>,
+        BitSlice<&[S::Word], B, Endian>,
+    )
+    where
+        B: Copy,
+        Endian: Copy,
+    {
+        self.try_split_at(mid).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Divides the slice into two at an index, or returns [`Error::IndexOutOfBounds`] if
+    /// `mid > len()`.
+    ///
+    /// See [`BitSlice::split_at`].
+    #[allow(clippy::type_complexity)]
+    pub fn try_split_at(
+        &self,
+        mid: usize,
+    ) -> Result<
+        (
+            BitSlice<&[S::Word], B, Endian>,
+            BitSlice<&[S::Word], B, Endian>,
+        ),
+        Error,
+    >
+    where
+        B: Copy,
+        Endian: Copy,
+    {
+        if mid > self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index: mid,
+                len: self.len(),
+            });
         }
+        Ok((self.slice(..mid), self.slice(mid..)))
     }
     /// Returns an [`Iterator`] over all bits in the slice.
     #[inline(always)]
-    pub fn iter(&self) -> BitIter<&[u8], B, Endian>
+    pub fn iter(&self) -> BitIter<&[S::Word], B, Endian>
     where
         B: Copy,
         Endian: Copy,
     {
-        BitIter {
-            slice: BitSlice {
-                bytes: self.bytes.as_ref(),
-                range: self.range.clone(),
-                bit_order: self.bit_order,
-                byte_order: self.byte_order,
-            },
-            idx: 0,
+        BitIter::new(self.slice(..))
+    }
+    /// Returns an [`Iterator`] over the indices of all bits that are set.
+    ///
+    /// Whole words of zeros are skipped at once.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, bits};
+    /// let bits: BitSlice<_> = bits![0, 1, 1, 0, 0, 0, 0, 0, 0, 1];
+    /// assert!(bits.iter_ones().eq([1, 2, 9]));
+    /// assert!(bits.iter_ones().rev().eq([9, 2, 1]));
+    /// ```
+    pub fn iter_ones(&self) -> BitIndices<&[S::Word], B, Endian>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        BitIndices::new(self.slice(..), true)
+    }
+    /// Returns an [`Iterator`] over the indices of all bits that are not set.
+    ///
+    /// Whole words of ones are skipped at once.
+    pub fn iter_zeros(&self) -> BitIndices<&[S::Word], B, Endian>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        BitIndices::new(self.slice(..), false)
+    }
+    /// Returns an [`Iterator`] over the runs of equal bits in the slice.
+    ///
+    /// Each run is yielded as its value and its range of indices.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, bits};
+    /// let bits: BitSlice<_> = bits![1, 1, 0, 0, 0, 1];
+    /// assert!(bits.runs().eq([(true, 0..2), (false, 2..5), (true, 5..6)]));
+    /// ```
+    pub fn runs(&self) -> BitRuns<&[S::Word], B, Endian>
+    where
+        B: Copy,
+        Endian: Copy,
+    {
+        BitRuns {
+            slice: self.slice(..),
+            front: 0,
+            back: self.len(),
         }
     }
-    fn to_uint(&self, max_bits: usize) -> Result<u64, Error>
+    /// Reads `n` (at most 64) bits starting at bit `start` of the slice.
+    ///
+    /// Bit `i` of the result is bit `start + i` of the slice. The bits are loaded a word at a time
+    /// instead of being located one by one.
+    pub(crate) fn read_bits(&self, start: usize, n: usize) -> u64
     where
         B: BitOrder,
         Endian: ByteOrder,
     {
-        let mut v = 0;
-        for i in 0..self.range.len() {
-            v <<= 1;
-            let b = self.get_bit(self.range.end - i - 1);
-            if i >= max_bits && b {
-                return Err(Error::ConversionError);
+        debug_assert!(n <= 64 && start + n <= self.len());
+        if n == 0 {
+            return 0;
+        }
+        let (bit_order, layout) = (self.bit_order, self.layout());
+        let bytes = self.storage();
+        let pos = self.range.start + start;
+        let (first, shift) = (pos / 8, pos % 8);
+        let count = (shift + n).div_ceil(8);
+        let word = if count <= 8 {
+            bit_order.reorder(layout.load(bit_order, bytes, first, count)) >> shift
+        } else {
+            // The bits straddle 9 bytes, so `shift` is non-zero here.
+            let lo = bit_order.reorder(layout.load(bit_order, bytes, first, 8));
+            let hi = bit_order.reorder(layout.load(bit_order, bytes, first + 8, 1));
+            (lo >> shift) | (hi << (64 - shift))
+        };
+        word & low_mask(n)
+    }
+    /// Compares `len` bits of `self` starting at `start` with the bits of `other` starting at
+    /// `other_start`, a word at a time.
+    pub(crate) fn bits_eq<S2: BitStorage>(
+        &self,
+        start: usize,
+        other: &BitSlice<S2, B, Endian>,
+        other_start: usize,
+        len: usize,
+    ) -> bool
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(64);
+            if self.read_bits(start + i, n) != other.read_bits(other_start + i, n) {
+                return false;
+            }
+            i += n;
+        }
+        true
+    }
+    /// Reads `n` (at most 128) bits starting at bit `start` of the slice, see
+    /// [`BitSlice::read_bits`].
+    fn read_u128(&self, start: usize, n: usize) -> u128
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let lo = n.min(64);
+        self.read_bits(start, lo) as u128 | (self.read_bits(start + lo, n - lo) as u128) << 64
+    }
+    /// Returns `true` if all bits in `range` are equal to `value`.
+    fn all_equal(&self, range: Range<usize>, value: bool) -> bool
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let word = if value { u64::MAX } else { 0 };
+        let mut i = range.start;
+        while i < range.end {
+            let n = (range.end - i).min(64);
+            if self.read_bits(i, n) != word & low_mask(n) {
+                return false;
             }
-            v += b as u64;
+            i += n;
+        }
+        true
+    }
+    /// Reads the bits in `start..end` as a two's complement integer of at most 128 bits, see
+    /// [`BitSlice::split_field`] for `msb_first`.
+    fn read_int(&self, start: usize, end: usize, msb_first: bool) -> Result<i128, Error>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let n = (end - start).min(128);
+        if n == 0 {
+            return Ok(0);
+        }
+        let (value_start, excess) = self.split_field(start, end, n, msb_first);
+        // The bits that do not fit into the value must repeat its sign.
+        let sign = self.get_bit(if msb_first {
+            value_start
+        } else {
+            value_start + n - 1
+        });
+        if !self.all_equal(excess, sign) {
+            return Err(Error::ConversionError);
+        }
+        let raw = self.read_uint(value_start, value_start + n, 128, msb_first)?;
+        Ok(((raw << (128 - n)) as i128) >> (128 - n))
+    }
+    /// Reads the bits in `start..end` as an unsigned integer of at most `max_bits` bits, see
+    /// [`BitSlice::split_field`] for `msb_first`.
+    fn read_uint(
+        &self,
+        start: usize,
+        end: usize,
+        max_bits: usize,
+        msb_first: bool,
+    ) -> Result<u128, Error>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let n = (end - start).min(max_bits);
+        let (value_start, excess) = self.split_field(start, end, n, msb_first);
+        // The bits that do not fit into the value must be zero.
+        if !self.all_equal(excess, false) {
+            return Err(Error::ConversionError);
+        }
+        let value = self.read_u128(value_start, n);
+        if msb_first && n > 0 {
+            Ok(value.reverse_bits() >> (128 - n))
+        } else {
+            Ok(value)
+        }
+    }
+    /// Reads the bits in `range` as an unsigned integer.
+    ///
+    /// The bit order decides which bit is the most significant one: with [`Lsb0`] the first bit
+    /// of the range is the least significant bit of the value, with [`Msb0`] it is the most
+    /// significant bit. Byte aligned fields are therefore read as little endian integers with
+    /// [`Lsb0`]/[`LittleEndian`] and as big endian integers with [`Msb0`]/[`LittleEndian`] or
+    /// [`Lsb0`]/[`BigEndian`].
+    ///
+    /// # Arguments
+    /// * `range` - The range of bits to read.
+    ///
+    /// # Returns
+    /// The value of the field, [`Error::ConversionError`] if it does not fit into `T` or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Msb0};
+    /// let data = [0b1010_0101u8, 0b1100_0011];
+    /// let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+    /// assert_eq!(bits.get_field::<u8>(4..12), Ok(0b0101_1100));
+    /// assert_eq!(bits.get_field::<u16>(..), Ok(0xa5c3));
+    /// ```
+    pub fn get_field<T: Unsigned>(&self, range: impl RangeBounds<usize>) -> Result<T, Error>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let (start, end) = self.try_bit_range(range)?;
+        self.read_uint(start, end, T::BITS, self.bit_order.msb_first())
+            .map(T::from_u128)
+    }
+    /// Reads the bits in `range` as a two's complement signed integer.
+    ///
+    /// The most significant bit of the field, as decided by the bit order (see
+    /// [`BitSlice::get_field`]), is the sign bit and the value is sign-extended from it.
+    ///
+    /// # Arguments
+    /// * `range` - The range of bits to read.
+    ///
+    /// # Returns
+    /// The value of the field, [`Error::ConversionError`] if it does not fit into `T` or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Msb0};
+    /// // A 12-bit temperature of -2 followed by 4 other bits.
+    /// let data = [0xff, 0xe5];
+    /// let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+    /// assert_eq!(bits.get_signed_field::<i16>(0..12), Ok(-2));
+    /// assert_eq!(bits.get_signed_field::<i8>(12..16), Ok(5));
+    /// ```
+    pub fn get_signed_field<T: Signed>(&self, range: impl RangeBounds<usize>) -> Result<T, Error>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let (start, end) = self.try_bit_range(range)?;
+        let value = self.read_int(start, end, self.bit_order.msb_first())?;
+        T::from_i128(value).ok_or(Error::ConversionError)
+    }
+    /// Reads the bits in `range` as a sign-magnitude signed integer.
+    ///
+    /// The most significant bit of the field is the sign and the remaining bits are the
+    /// magnitude. Both zeros decode to `0`.
+    ///
+    /// # Arguments
+    /// * `range` - The range of bits to read.
+    ///
+    /// # Returns
+    /// The value of the field, [`Error::ConversionError`] if it does not fit into `T` or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds.
+    pub fn get_sign_magnitude_field<T: Signed>(
+        &self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<T, Error>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let (start, end) = self.try_bit_range(range)?;
+        if start == end {
+            return T::from_i128(0).ok_or(Error::ConversionError);
+        }
+        let msb_first = self.bit_order.msb_first();
+        let (sign, magnitude) = if msb_first {
+            (start, start + 1..end)
+        } else {
+            (end - 1, start..end - 1)
+        };
+        let magnitude = self.read_uint(magnitude.start, magnitude.end, 127, msb_first)? as i128;
+        let value = if self.get_bit(sign) {
+            -magnitude
+        } else {
+            magnitude
+        };
+        T::from_i128(value).ok_or(Error::ConversionError)
+    }
+    /// Reads the bits in `range` as a one's complement signed integer.
+    ///
+    /// Negative values are stored as the inverted bits of their magnitude. Both zeros decode to
+    /// `0`.
+    ///
+    /// # Arguments
+    /// * `range` - The range of bits to read.
+    ///
+    /// # Returns
+    /// The value of the field, [`Error::ConversionError`] if it does not fit into `T` or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds.
+    pub fn get_ones_complement_field<T: Signed>(
+        &self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<T, Error>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        let (start, end) = self.try_bit_range(range)?;
+        let value = self.read_int(start, end, self.bit_order.msb_first())?;
+        // Two's complement is one's complement plus one for negative values.
+        let value = if value < 0 { value + 1 } else { value };
+        T::from_i128(value).ok_or(Error::ConversionError)
+    }
+    /// Converts the [BitSlice] to a [`u64`].
+    ///
+    /// The first bit of the slice is the least significant bit of the value, whatever the bit
+    /// order. Use [`BitSlice::get_field`] to read the slice as a field instead.
+    ///
+    /// # Panics
+    /// Panics if the value does not fit into a [`u64`]. See [`BitSlice::try_to_u64`] for a
+    /// non-panicking version.
+    #[inline(always)]
+    #[track_caller]
+    pub fn to_u64(&self) -> u64
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        self.try_to_u64().expect("Int too big!")
+    }
+    /// Converts the [BitSlice] to a [`u64`], see [`BitSlice::to_u64`].
+    ///
+    /// # Returns
+    /// The value, or [`Error::ConversionError`] if it does not fit into a [`u64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{bits, BitSlice, Error, LittleEndian, Msb0};
+    /// let bits: BitSlice<_, Msb0, LittleEndian> = bits![1, 0, 1, 1];
+    /// assert_eq!(bits.try_to_u64(), Ok(0b1101));
+    /// let x = [0u8, 0, 0, 0, 0, 0, 0, 0, 1];
+    /// let bits: BitSlice<_> = x.as_ref().into();
+    /// assert_eq!(bits.try_to_u64(), Err(Error::ConversionError));
+    /// ```
+    pub fn try_to_u64(&self) -> Result<u64, Error>
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        self.read_uint(0, self.len(), 64, false).map(|v| v as u64)
+    }
+    /// Converts the [BitSlice] to string of bits.
+    ///
+    /// This is the same as formatting it with [`Display`](core::fmt::Display).
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> alloc::string::String
+    where
+        B: BitOrder,
+        Endian: ByteOrder,
+    {
+        alloc::format!("{self}")
+    }
+}
+impl<S: BitStorageMut, B: BitOrder, Endian: ByteOrder> BitSlice<S, B, Endian> {
+    /// Returns the memory of the storage as mutable bytes.
+    #[inline(always)]
+    pub(crate) fn storage_mut(&mut self) -> &mut [u8] {
+        words_as_bytes_mut(self.bytes.as_words_mut())
+    }
+    /// Sets the value of a bit at a specified index.
+    ///
+    /// # Arguments
+    /// * `n` - The index of the bit to set.
+    /// * `value` - The value to set the bit to (`true` for set, `false` for clear).
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds. See [`BitSlice::try_set_bit`] for a non-panicking
+    /// version.
+    #[track_caller]
+    pub fn set_bit(&mut self, n: usize, value: bool) {
+        assert!(
+            n < self.len(),
+            "{}",
+            Error::IndexOutOfBounds {
+                index: n,
+                len: self.len()
+            }
+        );
+        let (bit_order, layout, pos) = (self.bit_order, self.layout(), self.range.start + n);
+        let bytes = self.storage_mut();
+        let (byte, bit) = bit_order.find_bit(layout, pos, bytes.len() * 8);
+        if value {
+            bytes[byte] |= 1 << bit;
+        } else {
+            bytes[byte] &= !(1 << bit);
+        }
+    }
+    /// Sets the value of a bit at a specified index, or returns [`Error::IndexOutOfBounds`] if
+    /// `n` is out of bounds.
+    ///
+    /// See [`BitSlice::set_bit`].
+    pub fn try_set_bit(&mut self, n: usize, value: bool) -> Result<(), Error> {
+        if n >= self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index: n,
+                len: self.len(),
+            });
+        }
+        self.set_bit(n, value);
+        Ok(())
+    }
+    /// Returns a mutable [BitSlice] representing a sub-slice of the current slice.
+    ///
+    /// # Arguments
+    /// * `range` - The range of bits to include in the sub-slice.
+    ///
+    /// # Returns
+    /// A new [BitSlice] representing the specified range, which writes into the same storage.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, BigEndian, Msb0};
+    /// let mut data = [0u8; 3];
+    /// let mut bits: BitSlice<_, Msb0, BigEndian> = data.as_mut().into();
+    /// bits.slice_mut(3..17).set_field(.., 0x2abcu16).unwrap();
+    /// assert_eq!(bits.get_field::<u16>(3..17), Ok(0x2abc));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds. See [`BitSlice::try_slice_mut`] for a non-panicking
+    /// version.
+    #[track_caller]
+    pub fn slice_mut(
+        &mut self,
+        range: impl RangeBounds<usize>,
+    ) -> BitSlice<&mut [S::Word], B, Endian> {
+        self.try_slice_mut(range).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Returns a mutable [BitSlice] representing a sub-slice of the current slice, or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds.
+    ///
+    /// See [`BitSlice::slice_mut`].
+    pub fn try_slice_mut(
+        &mut self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<BitSlice<&mut [S::Word], B, Endian>, Error> {
+        let (start_bit, end_excl_bit) = self.try_bit_range(range)?;
+        Ok(BitSlice {
+            bytes: self.bytes.as_words_mut(),
+            range: (self.range.start + start_bit)..(self.range.start + end_excl_bit),
+            bit_order: self.bit_order,
+            byte_order: self.byte_order,
+        })
+    }
+    /// Divides the slice into two mutable slices at an index.
+    ///
+    /// The split point may fall anywhere, even in the middle of a byte of the storage. The two
+    /// slices therefore share their storage as [`SharedBytes`], through which every write is a
+    /// read-modify-write of the bytes holding the written bits, so writes to one slice never
+    /// change the bits of the other one. Splits at the start of a byte can use
+    /// [`BitSlice::split_at_mut_aligned`] instead, whose slices keep the whole API of
+    /// [`BitSlice`].
+    ///
+    /// The two slices only offer a part of the API of [`BitSlice`]:
+    /// * They can get and set single bits and fields, iterate over their bits with `iter_mut`,
+    ///   and be sliced and split again.
+    /// * Every other access goes through `read` and `modify`, which copy the accessed bits.
+    /// * A single access, including `get_field` and `set_field`, covers at most 128 bits. Wider
+    ///   accesses return [`Error::StorageTooSmall`].
+    /// * [`SharedBytes`] does not implement [`BitStorageMut`], so the slices cannot be the target
+    ///   of [`BitPack::pack`], `invert`, the shifts and rotations, the bitwise assignment
+    ///   operators or a [`BitWriter`].
+    ///
+    /// # Arguments
+    /// * `mid` - The index of the first bit of the second slice.
+    ///
+    /// # Returns
+    /// A mutable [BitSlice] over the bits `..mid` and one over the bits `mid..`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len()`. See [`BitSlice::try_split_at_mut`] for a non-panicking version.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Lsb0};
+    /// let mut data = [0u8; 3];
+    /// let mut bits: BitSlice<_, Lsb0, LittleEndian> = data.as_mut().into();
+    /// let (mut head, mut body) = bits.split_at_mut(4);
+    /// head.set_field(.., 0xau8).unwrap();
+    /// body.set_field(..16, 0x1234u16).unwrap();
+    /// body.set_bit(19, true);
+    /// assert_eq!(data, [0x4a, 0x23, 0x81]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (
+        BitSlice<SharedBytes<'_, S::Word>, B, Endian>,
+        BitSlice<SharedBytes<'_, S::Word>, B, Endian>,
+    ) {
+        self.try_split_at_mut(mid).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Divides the slice into two mutable slices at an index, or returns
+    /// [`Error::IndexOutOfBounds`] if `mid > len()`.
+    ///
+    /// See [`BitSlice::split_at_mut`].
+    #[allow(clippy::type_complexity)]
+    pub fn try_split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> Result<
+        (
+            BitSlice<SharedBytes<'_, S::Word>, B, Endian>,
+            BitSlice<SharedBytes<'_, S::Word>, B, Endian>,
+        ),
+        Error,
+    > {
+        let (start, end) = (self.range.start, self.range.end);
+        let (bit_order, byte_order) = (self.bit_order, self.byte_order);
+        shared::split(
+            Cell::from_mut(self.storage_mut()).as_slice_of_cells(),
+            start,
+            end,
+            mid,
+            bit_order,
+            byte_order,
+        )
+    }
+    /// Divides the slice into two mutable slices at an index that starts a word of the storage.
+    ///
+    /// Unlike [`BitSlice::split_at_mut`] the two slices do not share a word, so each of them
+    /// borrows its part of the storage mutably and keeps the whole API of [`BitSlice`], such as
+    /// the bitwise operators, the searches and [`BitWriter`]. Bit `mid` must start a byte of the
+    /// storage, or a word with [`Words`]. A slice that starts in the middle of a byte can still be
+    /// split, as long as `mid` is chosen so that the second slice starts on a byte boundary.
+    ///
+    /// # Arguments
+    /// * `mid` - The index of the first bit of the second slice.
+    ///
+    /// # Returns
+    /// A mutable [BitSlice] over the bits `..mid` and one over the bits `mid..`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len()` or if bit `mid` does not start a word of the storage. See
+    /// [`BitSlice::try_split_at_mut_aligned`] for a non-panicking version.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Lsb0};
+    /// let mut data = [0u8; 1024];
+    /// let mut bits: BitSlice<_, Lsb0, LittleEndian> = data.as_mut().into();
+    /// let (mut head, mut body) = bits.split_at_mut_aligned(4096);
+    /// body.invert();
+    /// head.set_bit(0, true);
+    /// assert_eq!(body.count_ones(), 4096);
+    /// assert_eq!((data[0], data[511], data[512]), (0x01, 0x00, 0xff));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn split_at_mut_aligned(
+        &mut self,
+        mid: usize,
+    ) -> (
+        BitSlice<&mut [S::Word], B, Endian>,
+        BitSlice<&mut [S::Word], B, Endian>,
+    ) {
+        self.try_split_at_mut_aligned(mid)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Divides the slice into two mutable slices at an index that starts a word of the storage,
+    /// or returns [`Error::IndexOutOfBounds`] if `mid > len()` or [`Error::Unaligned`] if bit
+    /// `mid` does not start a word.
+    ///
+    /// See [`BitSlice::split_at_mut_aligned`].
+    #[allow(clippy::type_complexity)]
+    pub fn try_split_at_mut_aligned(
+        &mut self,
+        mid: usize,
+    ) -> Result<
+        (
+            BitSlice<&mut [S::Word], B, Endian>,
+            BitSlice<&mut [S::Word], B, Endian>,
+        ),
+        Error,
+    > {
+        if mid > self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index: mid,
+                len: self.len(),
+            });
+        }
+        let word_bytes = self.layout().word_bytes();
+        let pos = self.range.start + mid;
+        if !pos.is_multiple_of(8 * word_bytes) {
+            return Err(Error::Unaligned {
+                index: mid,
+                alignment: 8 * word_bytes,
+            });
+        }
+        let starts_at_end = self.byte_order.starts_at_end();
+        let words = self.bytes.as_words_mut();
+        let (total, first) = (words.len(), pos / S::Word::BITS);
+        // Depending on the byte order the first bits are at the start or the end of the storage.
+        // In both cases the words keep their position relative to the used end of their part.
+        let (head, tail) = if starts_at_end {
+            let (tail, head) = words.split_at_mut(total - first);
+            (head, tail)
+        } else {
+            words.split_at_mut(first)
+        };
+        Ok((
+            BitSlice {
+                bytes: head,
+                range: self.range.start..pos,
+                bit_order: self.bit_order,
+                byte_order: self.byte_order,
+            },
+            BitSlice {
+                bytes: tail,
+                range: 0..self.range.end - pos,
+                bit_order: self.bit_order,
+                byte_order: self.byte_order,
+            },
+        ))
+    }
+    /// Writes the `n` (at most 64) least significant bits of `value` starting at bit `start`.
+    ///
+    /// This is the inverse of [`BitSlice::read_bits`]. Only the bytes covering the written bits
+    /// are touched, and each of them is read and written once.
+    pub(crate) fn write_bits(&mut self, start: usize, n: usize, value: u64) {
+        debug_assert!(n <= 64 && start + n <= self.len());
+        if n == 0 {
+            return;
+        }
+        let (bit_order, byte_order) = (self.bit_order, self.layout());
+        let pos = self.range.start + start;
+        let bytes = self.storage_mut();
+        let (first, shift) = (pos / 8, pos % 8);
+        let count = (shift + n).div_ceil(8);
+        let value = value & low_mask(n);
+        if count <= 8 {
+            let mask = low_mask(n) << shift;
+            let word = bit_order.reorder(byte_order.load(bit_order, bytes, first, count));
+            let word = (word & !mask) | (value << shift);
+            byte_order.store(bit_order, bytes, first, count, bit_order.reorder(word));
+        } else {
+            // The bits straddle 9 bytes, so `shift` is non-zero here.
+            let lo = bit_order.reorder(byte_order.load(bit_order, bytes, first, 8));
+            let lo = (lo & low_mask(shift)) | (value << shift);
+            byte_order.store(bit_order, bytes, first, 8, bit_order.reorder(lo));
+            let hi = bit_order.reorder(byte_order.load(bit_order, bytes, first + 8, 1));
+            let hi = (hi & !low_mask(shift + n - 64)) | (value >> (64 - shift));
+            byte_order.store(bit_order, bytes, first + 8, 1, bit_order.reorder(hi));
+        }
+    }
+    /// Copies the bits in `src` to the bits starting at `dest`, like `<[T]>::copy_within`.
+    ///
+    /// The ranges may overlap.
+    pub(crate) fn copy_bits_within(&mut self, src: Range<usize>, dest: usize) {
+        let len = src.end - src.start;
+        if dest <= src.start {
+            let mut i = 0;
+            while i < len {
+                let n = (len - i).min(64);
+                let word = self.read_bits(src.start + i, n);
+                self.write_bits(dest + i, n, word);
+                i += n;
+            }
+        } else {
+            let mut i = len;
+            while i > 0 {
+                let n = i.min(64);
+                i -= n;
+                let word = self.read_bits(src.start + i, n);
+                self.write_bits(dest + i, n, word);
+            }
+        }
+    }
+    /// Sets all bits in `range` to `value`.
+    pub(crate) fn fill_bits(&mut self, range: Range<usize>, value: bool) {
+        let word = if value { u64::MAX } else { 0 };
+        let mut i = range.start;
+        while i < range.end {
+            let n = (range.end - i).min(64);
+            self.write_bits(i, n, word);
+            i += n;
         }
-        Ok(v)
     }
-    #[inline(always)]
-    pub fn to_u64(&self) -> u64
-    where
-        B: BitOrder,
-        Endian: ByteOrder,
-    {
-        self.to_uint(64).expect("Int too big!")
+    /// Writes the low bits of `value` to the bits in `start..end` and fills the bits that do not
+    /// fit into a `u128` with `fill`, see [`BitSlice::split_field`] for `msb_first`.
+    fn write_raw(&mut self, start: usize, end: usize, value: u128, fill: bool, msb_first: bool) {
+        let n = (end - start).min(128);
+        let (value_start, excess) = self.split_field(start, end, n, msb_first);
+        let value = if msb_first && n > 0 {
+            value.reverse_bits() >> (128 - n)
+        } else {
+            value
+        };
+        self.fill_bits(excess, fill);
+        let lo = n.min(64);
+        self.write_bits(value_start, lo, value as u64);
+        self.write_bits(value_start + lo, n - lo, (value >> 64) as u64);
     }
-    /// Converts the [BitSlice] to string of bits.
-    #[cfg(feature = "alloc")]
-    pub fn to_string(&self) -> alloc::string::String
-    where
-        B: BitOrder,
-        Endian: ByteOrder,
-    {
-        (0..self.range.len())
-            .clone()
-            .map(|n| if self.get_bit(n) { '1' } else { '0' })
-            .collect()
+    /// Writes an unsigned integer to the bits in `range`.
+    ///
+    /// This is the inverse of [`BitSlice::get_field`], so the bit order decides which bit of the
+    /// range receives the most significant bit of `value`. If the range is wider than `T` the
+    /// remaining high bits are cleared.
+    ///
+    /// # Arguments
+    /// * `range` - The range of bits to write.
+    /// * `value` - The value to write.
+    ///
+    /// # Returns
+    /// [`Error::Overflow`] if `value` does not fit into the range or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds, in which case nothing is written.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Msb0};
+    /// let mut data = [0u8; 2];
+    /// let mut bits: BitSlice<_, Msb0, LittleEndian> = data.as_mut().into();
+    /// bits.set_field(3..13, 0x1ffu16).unwrap();
+    /// assert!(bits.set_field(0..3, 8u8).is_err());
+    /// assert_eq!(data, [0b0000_1111, 0b1111_1000]);
+    /// ```
+    pub fn set_field<T: Unsigned>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        let (start, end) = self.try_bit_range(range)?;
+        let value = value.to_u128();
+        if end - start < 128 && value >> (end - start) != 0 {
+            return Err(Error::Overflow);
+        }
+        self.write_raw(start, end, value, false, self.bit_order.msb_first());
+        Ok(())
     }
-}
-impl<S: AsMut<[u8]>, B: BitOrder, Endian: ByteOrder> BitSlice<S, B, Endian> {
-    /// Sets the value of a bit at a specified index.
+    /// Writes a two's complement signed integer to the bits in `range`.
+    ///
+    /// This is the inverse of [`BitSlice::get_signed_field`]. If the range is wider than `T` the
+    /// value is sign-extended.
     ///
     /// # Arguments
-    /// * `n` - The index of the bit to set.
-    /// * `value` - The value to set the bit to (`true` for set, `false` for clear).
+    /// * `range` - The range of bits to write.
+    /// * `value` - The value to write.
     ///
-    /// # Panics
-    /// Panics if `n` is out of bounds.
-    pub fn set_bit(&mut self, n: usize, value: bool) {
-        assert!(n < self.range.len());
-        let (byte, bit) = self.bit_order.find_bit(
-            self.byte_order,
-            self.range.start + n,
-            self.bytes.as_mut().len() * 8,
-        );
-        if value {
-            self.bytes.as_mut()[byte] |= 1 << bit;
-        } else {
-            self.bytes.as_mut()[byte] &= !(1 << bit);
+    /// # Returns
+    /// [`Error::Overflow`] if `value` does not fit into the range or
+    /// [`Error::RangeOutOfBounds`] if `range` is out of bounds, in which case nothing is written.
+    pub fn set_signed_field<T: Signed>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        let (start, end) = self.try_bit_range(range)?;
+        let value = value.to_i128();
+        let n = end - start;
+        let fits = match n {
+            0 => value == 0,
+            1..128 => (-(1 << (n - 1))..1 << (n - 1)).contains(&value),
+            _ => true,
+        };
+        if !fits {
+            return Err(Error::Overflow);
+        }
+        let msb_first = self.bit_order.msb_first();
+        self.write_raw(start, end, value as u128, value < 0, msb_first);
+        Ok(())
+    }
+    /// Returns an [`Iterator`] over mutable references to all bits in the slice.
+    ///
+    /// See [`BitMut`] for how the bits are written.
+    pub fn iter_mut(&mut self) -> BitIterMut<'_, B, Endian, S::Word> {
+        let (range, bit_order, byte_order) = (self.range.clone(), self.bit_order, self.byte_order);
+        BitIterMut {
+            bytes: Cell::from_mut(self.storage_mut()).as_slice_of_cells(),
+            range,
+            bit_order,
+            byte_order: Layout::new(byte_order),
+        }
+    }
+    /// Calls `f` with the index and a mutable reference to the value of every bit in the slice.
+    ///
+    /// The bits are read and written a word at a time, which makes this faster than
+    /// [`BitSlice::iter_mut`] for updating many bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Lsb0};
+    /// let mut data = [0b1010_1010u8, 0];
+    /// let mut bits: BitSlice<_, Lsb0, LittleEndian> = data.as_mut().into();
+    /// bits.for_each_mut(|i, bit| *bit ^= i >= 4);
+    /// assert_eq!(data, [0b0101_1010, 0xff]);
+    /// ```
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(usize, &mut bool)) {
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(64);
+            let old = self.read_bits(i, n);
+            let mut word = 0;
+            for k in 0..n {
+                let mut bit = old & (1 << k) != 0;
+                f(i + k, &mut bit);
+                word |= (bit as u64) << k;
+            }
+            self.write_bits(i, n, word);
+            i += n;
         }
     }
     pub fn push(&mut self, value: bool) -> Result<(), Error> {
-        if self.range.end >= self.bytes.as_mut().len() * 8 {
+        if self.range.end >= self.storage().len() * 8 {
             return Err(Error::Overflow);
         }
         if self.range.end == 0 {
             // clear array at first push
-            self.bytes.as_mut().fill(0);
+            self.storage_mut().fill(0);
         }
         self.range.end += 1;
         self.set_bit(self.range.end - 1, value);
         Ok(())
     }
-    pub fn pop(&mut self) -> Option<bool>
-    where
-        S: AsRef<[u8]>,
-    {
+    pub fn pop(&mut self) -> Option<bool> {
         if self.range.start == self.range.end {
             return None;
         }
@@ -292,7 +1502,7 @@ impl<S: AsMut<[u8]>, B: BitOrder, Endian: ByteOrder> BitSlice<S, B, Endian> {
     }
 }
 
-impl<S: AsRef<[u8]> + Clone, B: Copy, Endian: Copy> Clone for BitSlice<S, B, Endian> {
+impl<S: BitStorage + Clone, B: Copy, Endian: Copy> Clone for BitSlice<S, B, Endian> {
     fn clone(&self) -> Self {
         BitSlice {
             bytes: self.bytes.clone(),
@@ -303,16 +1513,17 @@ impl<S: AsRef<[u8]> + Clone, B: Copy, Endian: Copy> Clone for BitSlice<S, B, End
 }
 
 // Implementation of `TryFrom<BitSlice>` for all unsigned integer types
+//
+// The first bit of the slice is the least significant bit of the value in every bit order, unlike
+// in `BitSlice::get_field`. Bits beyond the width of the type must be zero.
 macro_rules! impl_try_from_bitslice {
     ($($t:ty),*) => {
         $(
-            impl<S: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder> TryFrom<BitSlice<S, B, Endian>> for $t {
+            impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> TryFrom<BitSlice<S, B, Endian>> for $t {
                 type Error = Error;
                 #[inline(always)]
                 fn try_from(value: BitSlice<S, B, Endian>) -> Result<Self, Self::Error> {
-                    value
-                        .to_uint(Self::BITS as usize)
-                        .and_then(|v| v.try_into().map_err(|_| Error::ConversionError))
+                    value.read_uint(0, value.len(), Self::BITS as usize, false).map(|v| v as Self)
                 }
             }
         )*
@@ -320,24 +1531,39 @@ macro_rules! impl_try_from_bitslice {
 }
 impl_try_from_bitslice!(u8, u16, u32, u64, u128, usize);
 
+// Implementation of `TryFrom<BitSlice>` for all signed integer types
+//
+// Like the unsigned conversions, the value is sign-extended from the last bit of the slice.
+macro_rules! impl_try_from_bitslice_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> TryFrom<BitSlice<S, B, Endian>> for $t {
+                type Error = Error;
+                #[inline(always)]
+                fn try_from(value: BitSlice<S, B, Endian>) -> Result<Self, Self::Error> {
+                    let v = value.read_int(0, value.len(), false)?;
+                    v.try_into().map_err(|_| Error::ConversionError)
+                }
+            }
+        )*
+    };
+}
+impl_try_from_bitslice_signed!(i8, i16, i32, i64, i128, isize);
+
 // Implementation of `Into<BitSlice>` for all unsigned integer types
+//
+// The least significant bit of the value becomes the first bit of the slice.
 macro_rules! impl_into_bitslice {
     ($($t:ty),*) => {
         $(
             impl<B: BitOrder + Default, Endian: ByteOrder + Default> From<$t>
                 for BitSlice<[u8; (<$t>::BITS / 8) as usize], B, Endian>
             {
-                fn from(mut value: $t) -> Self {
+                fn from(value: $t) -> Self {
                     const BITS: usize = <$t>::BITS as _;
                     let arr = [0; (BITS / 8) as usize];
                     let mut slice: BitSlice<_, B, Endian> = BitSlice::new(arr, BITS);
-                    for idx in 0..BITS {
-                        if value == 0 {
-                            break;
-                        }
-                        slice.set_bit(idx, (value & 1) == 1);
-                        value >>= 1;
-                    }
+                    slice.write_raw(0, BITS, value as u128, false, false);
                     slice
                 }
             }
@@ -346,22 +1572,38 @@ macro_rules! impl_into_bitslice {
 }
 impl_into_bitslice!(u8, u16, u32, u64, u128, usize);
 
-impl<S: AsRef<[u8]>, S2: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder>
+// Implementation of `Into<BitSlice>` for all signed integer types
+macro_rules! impl_into_bitslice_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl<B: BitOrder + Default, Endian: ByteOrder + Default> From<$t>
+                for BitSlice<[u8; (<$t>::BITS / 8) as usize], B, Endian>
+            {
+                #[inline(always)]
+                fn from(value: $t) -> Self {
+                    (value as $u).into()
+                }
+            }
+        )*
+    };
+}
+impl_into_bitslice_signed!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize
+);
+
+impl<S: BitStorage, S2: BitStorage, B: BitOrder, Endian: ByteOrder>
     PartialEq<BitSlice<S2, B, Endian>> for BitSlice<S, B, Endian>
 {
     fn eq(&self, other: &BitSlice<S2, B, Endian>) -> bool {
-        if self.len() != other.len() {
-            return false;
-        }
-        for i in 0..self.len() {
-            if self.get_bit(i) != other.get_bit(i) {
-                return false;
-            }
-        }
-        true
+        self.len() == other.len() && self.bits_eq(0, other, 0, self.len())
     }
 }
-impl<S: AsRef<[u8]>, T: AsRef<[bool]>, B: BitOrder, Endian: ByteOrder> PartialEq<T>
+impl<S: BitStorage, T: AsRef<[bool]>, B: BitOrder, Endian: ByteOrder> PartialEq<T>
     for BitSlice<S, B, Endian>
 {
     fn eq(&self, other: &T) -> bool {
@@ -377,59 +1619,226 @@ impl<S: AsRef<[u8]>, T: AsRef<[bool]>, B: BitOrder, Endian: ByteOrder> PartialEq
     }
 }
 
-#[cfg(feature = "alloc")]
-impl<S, B, Endian> core::fmt::Debug for BitSlice<S, B, Endian>
-where
-    S: AsRef<[u8]>,
-    B: BitOrder + core::fmt::Debug,
-    Endian: ByteOrder + core::fmt::Debug,
-{
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        // Start the debug struct.
-        let mut name = alloc::string::String::new();
-        let mut bits_field = alloc::string::String::new();
-        core::fmt::write(
-            &mut name,
-            core::format_args!("BitSlice<_, {:?}, {:?}>", self.bit_order, self.byte_order,),
-        )?;
-        core::fmt::write(
-            &mut bits_field,
-            core::format_args!("bits[{}..{}]", self.range.start, self.range.end),
-        )?;
-        f.debug_struct(&name)
-            // Add the bytes field.
-            .field("bytes", &self.bytes.as_ref())
-            // Add the bits range and their representation.
-            .field(&bits_field, &self.to_string())
-            .finish()
-    }
-}
-
 /// A macro to conveniently create a [BitSlice] from a list of boolean values.
 ///
+/// The bits can also be given as a string literal, which may separate them with underscores and
+/// whitespace, or as an integer value followed by the number of bits, which is laid out like
+/// [`BitSlice::from_uint`] lays it out.
+///
+/// The string and integer forms work in `const` and `static` items, so the bits need no
+/// initialization at runtime, and therefore need a bit and byte order that implements
+/// [`ConstBitOrder`] and [`ConstByteOrder`]. The list form, including a single integer or `bool`,
+/// sets the bits at runtime with [`BitSlice::set_bit`] and works with every bit and byte order
+/// that implements [`Default`].
+/// Lists of bits in `const` and `static` items can be created with [`BitSlice::from_bools`].
+///
 /// # Examples
 /// ```
-/// use bitslicer::{BitSlice, LittleEndian, Lsb0, bits};
+/// use bitslicer::{BitSlice, DynBitOrder, DynEndian, LittleEndian, Lsb0, Msb0, bits};
 /// let my_bits: BitSlice<_, Lsb0, LittleEndian> = bits![1, 0, 1];
+/// let dyn_bits: BitSlice<_, DynBitOrder, DynEndian> = bits![1, 0, 1];
+///
+/// static SYNC: BitSlice<[u8; 2], Msb0, LittleEndian> = bits!("1011_0010 1");
+/// const SHORT: BitSlice<[u8; 1], Msb0, LittleEndian> = bits!(0b1011; 6);
+/// assert!(SYNC == bits![1, 0, 1, 1, 0, 0, 1, 0, 1]);
+/// assert!(SHORT == bits![0, 0, 1, 0, 1, 1]);
 /// ```
 #[macro_export]
 macro_rules! bits {
-    ($($bit:expr),*) => {
-        {
-        // Create a temporary array to store the bits.
-        let array = [0u8; ($crate::count_expr!($($bit),*) + 7) /8];
+    ($value:expr; $len:expr) => {
+        $crate::BitSlice::<[u8; ($len as usize).div_ceil(8)], _, _>::from_uint(
+            $value as u128,
+            $len,
+        )
+    };
+    ($literal:literal) => {
+        $crate::__Literal($literal).bits::<
+            { $crate::__Literal($literal).num_bits().div_ceil(8) },
+            _,
+            _,
+        >()
+    };
+    ($($bit:expr),* $(,)?) => {{
+        let bits: &[bool] = &[$(($bit as usize) != 0),*];
+        let mut slice = $crate::BitSlice::new(
+            [0u8; $crate::count_expr!($($bit),*).div_ceil(8)],
+            $crate::count_expr!($($bit),*),
+        );
+        for (index, &bit) in bits.iter().enumerate() {
+            slice.set_bit(index, bit);
+        }
+        slice
+    }};
+}
+
+/// A macro that names the type of a [`BitArray`] of a number of bits, so that the number of
+/// bytes of its storage does not have to be given.
+///
+/// `bitarr!(BITS)` is a `BitArray<BITS, { BITS.div_ceil(8) }>`, and the bit and byte order can
+/// follow the number of bits like in `bitarr!(BITS, B, Endian)`.
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BigEndian, BitArray, Msb0, bitarr};
+/// static HEADER: bitarr!(20, Msb0, BigEndian) = BitArray::new([0x0f, 0x00, 0xa0]);
+/// let flags: bitarr!(12) = BitArray::default();
+/// assert_eq!((HEADER.len(), flags.len()), (20, 12));
+/// assert_eq!(HEADER.get_field::<u8>(..4), Ok(0xa));
+/// ```
+#[macro_export]
+macro_rules! bitarr {
+    ($bits:expr $(, $order:ty, $endian:ty)? $(,)?) => {
+        $crate::BitArray<{ $bits }, { ($bits as usize).div_ceil(8) } $(, $order, $endian)?>
+    };
+}
 
-        let mut slice = $crate::BitSlice::new(array, $crate::count_expr!($($bit),*));
+/// A macro to declare a newtype over `[u8; N]` with typed getters and setters for named ranges
+/// of bits, without the need for proc-macros.
+///
+/// Every field is declared as `getter, setter: Type = start..end;`. The getter returns
+/// `Result<Type, Error>` and the setter takes a `Type` and returns `Result<(), Error>`, both of
+/// which go through [`BitPack`] on a [`BitSlice`] created with [`BitSlice::new_with_order`].
+/// The bit and byte order can be given after the storage and default to [`Lsb0`] and
+/// [`LittleEndian`]. The bytes are private to the module declaring the type and are accessed
+/// through `from_bytes`, `into_bytes`, `bits` and `bits_mut`.
+///
+/// The ranges are checked at compile time to fit into the `N` bytes and not to overlap.
+///
+/// ```compile_fail
+/// bitslicer::bitfield! {
+///     struct Overlapping([u8; 1]) {
+///         low, set_low: u8 = 0..4;
+///         high, set_high: u8 = 3..8;
+///     }
+/// }
+/// ```
+///
+/// # Examples
+/// ```
+/// use bitslicer::{bitfield, BigEndian, Msb0};
+///
+/// bitfield! {
+///     /// The control register of a peripheral.
+///     #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+///     pub struct Control([u8; 2], Msb0, BigEndian) {
+///         /// Enables the peripheral.
+///         pub enable, set_enable: bool = 0..1;
+///         pub mode, set_mode: u8 = 1..4;
+///         pub offset, set_offset: i8 = 8..16;
+///     }
+/// }
+///
+/// let mut control = Control::default();
+/// control.set_enable(true).unwrap();
+/// control.set_mode(5).unwrap();
+/// control.set_offset(-2).unwrap();
+/// assert!(control.set_mode(8).is_err());
+/// assert_eq!(control.mode(), Ok(5));
+/// assert_eq!(control.into_bytes(), [0xfe, 0b1101_0000]);
+/// ```
+#[macro_export]
+macro_rules! bitfield {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident([u8; $n:expr]) {
+            $($fields:tt)*
+        }
+    ) => {
+        $crate::bitfield! {
+            $(#[$attr])*
+            $vis struct $name([u8; $n], $crate::Lsb0, $crate::LittleEndian) {
+                $($fields)*
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident([u8; $n:expr], $order:ty, $endian:ty) {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $getter:ident, $setter:ident: $t:ty = $range:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name([u8; $n]);
 
-        // Set each bit in the array.
-        let mut index: isize = -1;
-        $(
-            index += 1;
-            slice.set_bit(index as usize, ($bit as usize) != 0);
-        )*
+        // Check that the fields fit into the storage and do not overlap.
+        const _: () = {
+            let ranges: &[::core::ops::Range<usize>] = &[$($range),*];
+            let mut i = 0;
+            while i < ranges.len() {
+                assert!(
+                    ranges[i].start < ranges[i].end && ranges[i].end <= $n * 8,
+                    "bitfield range is empty or does not fit into the storage"
+                );
+                let mut j = 0;
+                while j < i {
+                    assert!(
+                        ranges[i].end <= ranges[j].start || ranges[j].end <= ranges[i].start,
+                        "bitfield ranges overlap"
+                    );
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
 
-        slice
-    }};
+        impl $name {
+            /// Creates a new value from its bytes.
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; $n]) -> Self {
+                Self(bytes)
+            }
+            /// Returns the bytes of the value.
+            #[inline(always)]
+            pub const fn into_bytes(self) -> [u8; $n] {
+                self.0
+            }
+            /// Returns a [BitSlice]($crate::BitSlice) over the bytes of the value.
+            #[inline(always)]
+            pub fn bits(&self) -> $crate::BitSlice<&[u8], $order, $endian> {
+                $crate::BitSlice::new_with_order(
+                    &self.0[..],
+                    $n * 8,
+                    <$order as ::core::default::Default>::default(),
+                    <$endian as ::core::default::Default>::default(),
+                )
+            }
+            /// Returns a mutable [BitSlice]($crate::BitSlice) over the bytes of the value.
+            #[inline(always)]
+            pub fn bits_mut(&mut self) -> $crate::BitSlice<&mut [u8], $order, $endian> {
+                $crate::BitSlice::new_with_order(
+                    &mut self.0[..],
+                    $n * 8,
+                    <$order as ::core::default::Default>::default(),
+                    <$endian as ::core::default::Default>::default(),
+                )
+            }
+            $(
+                $(#[$field_attr])*
+                #[inline]
+                $field_vis fn $getter(&self) -> ::core::result::Result<$t, $crate::Error> {
+                    <$t as $crate::BitPack<$order, $endian>>::unpack(&self.bits().slice($range))
+                }
+                #[doc = ::core::concat!(
+                    "Sets the bits read by `",
+                    ::core::stringify!($getter),
+                    "`."
+                )]
+                #[inline]
+                $field_vis fn $setter(
+                    &mut self,
+                    value: $t,
+                ) -> ::core::result::Result<(), $crate::Error> {
+                    <$t as $crate::BitPack<$order, $endian>>::pack(
+                        &value,
+                        &mut self.bits_mut().slice_mut($range),
+                    )
+                }
+            )*
+        }
+    };
 }
 
 /// Helper macro to count the number of expressions passed.
@@ -442,29 +1851,46 @@ macro_rules! count_expr {
 
 /// An iterator over the bits of a [BitSlice].
 ///
-/// This struct allows iterating over the bits in a [BitSlice], providing read (and optionally write) access to each bit.
+/// This struct allows iterating over the bits in a [BitSlice], providing read-only access to each
+/// bit. See [`BitIterMut`] for an iterator over mutable references to the bits.
 ///
 /// # Type Parameters
-/// - `S`: The underlying storage type, typically a byte slice.
+/// - `S`: The underlying storage type, typically a slice or an array of bytes or wider words, see
+///   [`BitStorage`].
 /// - `B`: The bit order.
 /// - `Endian`: The byte order.
 pub struct BitIter<S, B, Endian> {
     slice: BitSlice<S, B, Endian>,
+    /// The range of indices that were not yielded yet.
     idx: usize,
+    end: usize,
+    /// Bits following `idx` that were already loaded, starting at the least significant bit.
+    word: u64,
+    /// Number of valid bits in `word`.
+    word_len: usize,
 }
 
-impl<S: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder> IntoIterator for BitSlice<S, B, Endian> {
+impl<S, B, Endian> BitIter<S, B, Endian> {
+    #[inline(always)]
+    fn new(slice: BitSlice<S, B, Endian>) -> Self {
+        Self {
+            end: slice.len(),
+            slice,
+            idx: 0,
+            word: 0,
+            word_len: 0,
+        }
+    }
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> IntoIterator for BitSlice<S, B, Endian> {
     type Item = bool;
     type IntoIter = BitIter<S, B, Endian>;
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        BitIter {
-            slice: self,
-            idx: 0,
-        }
+        BitIter::new(self)
     }
 }
-impl<S: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder> IntoIterator for &BitSlice<S, B, Endian>
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> IntoIterator for &BitSlice<S, B, Endian>
 where
     BitSlice<S, B, Endian>: Clone,
 {
@@ -472,42 +1898,45 @@ where
     type IntoIter = BitIter<S, B, Endian>;
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        BitIter {
-            slice: self.clone(),
-            idx: 0,
-        }
-    }
-}
-impl<S: AsMut<[u8]>, B: BitOrder, Endian: ByteOrder> BitIter<S, B, Endian> {
-    pub fn set_next(&mut self, value: bool) {
-        self.slice.set_bit(self.idx, value);
-        self.idx += 1;
+        BitIter::new(self.clone())
     }
 }
-impl<S: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder> Iterator for BitIter<S, B, Endian> {
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> Iterator for BitIter<S, B, Endian> {
     type Item = bool;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.idx < self.slice.len() {
-            let bit = self.slice.get_bit(self.idx);
-            self.idx += 1;
-            Some(bit)
-        } else {
-            None
+        if self.word_len == 0 {
+            if self.idx >= self.end {
+                return None;
+            }
+            self.word_len = (self.end - self.idx).min(64);
+            self.word = self.slice.read_bits(self.idx, self.word_len);
         }
+        let bit = (self.word & 1) != 0;
+        self.word >>= 1;
+        self.word_len -= 1;
+        self.idx += 1;
+        Some(bit)
     }
     #[inline(always)]
-    fn last(self) -> Option<Self::Item>
+    fn last(mut self) -> Option<Self::Item>
     where
         Self: Sized,
     {
-        if self.len() > 0 {
-            Some(self.slice.get_bit(self.slice.len() - 1))
-        } else {
-            None
-        }
+        self.next_back()
     }
     #[inline(always)]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.idx = self.end;
+            self.word_len = 0;
+            return None;
+        }
+        if n < self.word_len {
+            self.word >>= n;
+            self.word_len -= n;
+        } else {
+            self.word_len = 0;
+        }
         self.idx += n;
         self.next()
     }
@@ -516,17 +1945,282 @@ impl<S: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder> Iterator for BitIter<S, B,
     where
         Self: Sized,
     {
-        self.slice.len() - self.idx
+        self.len()
     }
     #[inline(always)]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.slice.len() - self.idx;
+        let len = self.len();
         (len, Some(len))
     }
 }
-impl<S: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder> ExactSizeIterator for BitIter<S, B, Endian> {
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> DoubleEndedIterator for BitIter<S, B, Endian> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        // Loaded bits past the new end are ignored from now on.
+        self.word_len = self.word_len.min(self.end - self.idx);
+        Some(self.slice.get_bit(self.end))
+    }
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.end = self.idx;
+            self.word_len = 0;
+            return None;
+        }
+        self.end -= n;
+        self.next_back()
+    }
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> ExactSizeIterator for BitIter<S, B, Endian> {
     fn len(&self) -> usize {
-        self.slice.len() - self.idx
+        self.end - self.idx
+    }
+}
+
+/// A mutable reference to a single bit of a [BitSlice], yielded by [`BitSlice::iter_mut`].
+///
+/// The proxy holds a copy of the bit, which can be read and written through [`Deref`] and
+/// [`DerefMut`] or [`BitMut::set`]. The value is written back to the storage when the proxy is
+/// dropped.
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BitSlice, LittleEndian, Msb0};
+/// let mut data = [0u8];
+/// let mut bits: BitSlice<_, Msb0, LittleEndian> = data.as_mut().into();
+/// for (i, mut bit) in bits.iter_mut().enumerate() {
+///     *bit = i % 3 == 0;
+/// }
+/// assert_eq!(data, [0b1001_0010]);
+/// ```
+pub struct BitMut<'a> {
+    /// The byte containing the bit. Several proxies may point into the same byte, so it is
+    /// shared through a [`Cell`] instead of a mutable reference.
+    byte: &'a Cell<u8>,
+    mask: u8,
+    value: bool,
+}
+
+impl BitMut<'_> {
+    /// Sets the value of the bit.
+    #[inline(always)]
+    pub fn set(&mut self, value: bool) {
+        self.value = value;
+    }
+    /// Sets the value of the bit and returns its previous value.
+    #[inline(always)]
+    pub fn replace(&mut self, value: bool) -> bool {
+        core::mem::replace(&mut self.value, value)
+    }
+}
+impl Deref for BitMut<'_> {
+    type Target = bool;
+    #[inline(always)]
+    fn deref(&self) -> &bool {
+        &self.value
+    }
+}
+impl DerefMut for BitMut<'_> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut bool {
+        &mut self.value
+    }
+}
+impl Drop for BitMut<'_> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        let byte = self.byte.get();
+        self.byte.set(if self.value {
+            byte | self.mask
+        } else {
+            byte & !self.mask
+        });
+    }
+}
+
+/// An iterator over mutable references to the bits of a [BitSlice].
+///
+/// Created by [`BitSlice::iter_mut`].
+pub struct BitIterMut<'a, B, Endian, W = u8> {
+    bytes: &'a [Cell<u8>],
+    /// The storage positions of the bits that were not yielded yet.
+    range: Range<usize>,
+    bit_order: B,
+    byte_order: Layout<Endian, W>,
+}
+
+impl<'a, B: BitOrder, Endian: ByteOrder, W: BitStore> BitIterMut<'a, B, Endian, W> {
+    fn bit(&self, n: usize) -> BitMut<'a> {
+        let (byte, bit) = self
+            .bit_order
+            .find_bit(self.byte_order, n, self.bytes.len() * 8);
+        let byte = &self.bytes[byte];
+        BitMut {
+            byte,
+            mask: 1 << bit,
+            value: byte.get() & (1 << bit) != 0,
+        }
+    }
+}
+impl<'a, B: BitOrder, Endian: ByteOrder, W: BitStore> Iterator for BitIterMut<'a, B, Endian, W> {
+    type Item = BitMut<'a>;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.range.next()?;
+        Some(self.bit(n))
+    }
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = self.range.nth(n)?;
+        Some(self.bit(n))
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+impl<B: BitOrder, Endian: ByteOrder, W: BitStore> DoubleEndedIterator
+    for BitIterMut<'_, B, Endian, W>
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let n = self.range.next_back()?;
+        Some(self.bit(n))
+    }
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let n = self.range.nth_back(n)?;
+        Some(self.bit(n))
+    }
+}
+impl<B: BitOrder, Endian: ByteOrder, W: BitStore> ExactSizeIterator
+    for BitIterMut<'_, B, Endian, W>
+{
+}
+
+/// An iterator over the indices of the bits of a [BitSlice] that are equal to a given value.
+///
+/// Created by [`BitSlice::iter_ones`] and [`BitSlice::iter_zeros`].
+pub struct BitIndices<S, B, Endian> {
+    slice: BitSlice<S, B, Endian>,
+    value: bool,
+    /// The range of indices that were not yielded yet.
+    front: usize,
+    back: usize,
+    /// The number of matching bits in `front..back`.
+    remaining: usize,
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> BitIndices<S, B, Endian> {
+    fn new(slice: BitSlice<S, B, Endian>, value: bool) -> Self {
+        let remaining = if value {
+            slice.count_ones()
+        } else {
+            slice.count_zeros()
+        };
+        Self {
+            back: slice.len(),
+            slice,
+            value,
+            front: 0,
+            remaining,
+        }
+    }
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> Iterator for BitIndices<S, B, Endian> {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let idx = self.front
+            + self
+                .slice
+                .slice(self.front..self.back)
+                .find_first(self.value)?;
+        self.front = idx + 1;
+        self.remaining -= 1;
+        Some(idx)
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> DoubleEndedIterator
+    for BitIndices<S, B, Endian>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let idx = self.front
+            + self
+                .slice
+                .slice(self.front..self.back)
+                .find_last(self.value)?;
+        self.back = idx;
+        self.remaining -= 1;
+        Some(idx)
+    }
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> ExactSizeIterator for BitIndices<S, B, Endian> {}
+
+/// An iterator over the runs of equal bits in a [BitSlice].
+///
+/// Created by [`BitSlice::runs`].
+pub struct BitRuns<S, B, Endian> {
+    slice: BitSlice<S, B, Endian>,
+    /// The range of indices that were not yielded yet.
+    front: usize,
+    back: usize,
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> Iterator for BitRuns<S, B, Endian> {
+    type Item = (bool, Range<usize>);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let value = self.slice.get_bit(self.front);
+        let rest = self.slice.slice(self.front..self.back);
+        let end = rest
+            .find_first(!value)
+            .map_or(self.back, |i| self.front + i);
+        let run = self.front..end;
+        self.front = end;
+        Some((value, run))
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len.min(1), Some(len))
+    }
+}
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> DoubleEndedIterator for BitRuns<S, B, Endian> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let value = self.slice.get_bit(self.back - 1);
+        let rest = self.slice.slice(self.front..self.back);
+        let start = rest
+            .find_last(!value)
+            .map_or(self.front, |i| self.front + i + 1);
+        let run = start..self.back;
+        self.back = start;
+        Some((value, run))
+    }
+}
+
+/// Returns a mask with the `n` (at most 64) least significant bits set.
+#[inline(always)]
+pub(crate) const fn low_mask(n: usize) -> u64 {
+    if n >= 64 {
+        u64::MAX
+    } else {
+        (1 << n) - 1
     }
 }
 
@@ -540,24 +2234,41 @@ impl<S: AsRef<[u8]>, B: BitOrder, Endian: ByteOrder> ExactSizeIterator for BitIt
 /// - `len`: The length of the underlying byte sequence.
 ///
 /// # Returns
-/// A tuple containing the start index and exclusive end index.
+/// A tuple containing the start index and exclusive end index, or
+/// [`Error::RangeOutOfBounds`] if the range is out of bounds or its bounds overflow. Bounds that
+/// overflow are reported as [`usize::MAX`].
 const fn range_to_bounds(
     start_bound: Bound<usize>,
     end_bound: Bound<usize>,
     len: usize,
-) -> (usize, usize) {
+) -> Result<(usize, usize), Error> {
     let start_index = match start_bound {
-        Bound::Included(idx) => idx,
-        Bound::Excluded(idx) => idx + 1,
-        Bound::Unbounded => 0,
+        Bound::Included(idx) => Some(idx),
+        Bound::Excluded(idx) => idx.checked_add(1),
+        Bound::Unbounded => Some(0),
     };
     let end_index = match end_bound {
-        Bound::Included(idx) => idx + 1,
-        Bound::Excluded(idx) => idx,
-        Bound::Unbounded => len,
+        Bound::Included(idx) => idx.checked_add(1),
+        Bound::Excluded(idx) => Some(idx),
+        Bound::Unbounded => Some(len),
     };
-    (start_index, end_index)
+    match (start_index, end_index) {
+        (Some(start), Some(end)) if start <= end && end <= len => Ok((start, end)),
+        (start, end) => Err(Error::RangeOutOfBounds {
+            start: match start {
+                Some(start) => start,
+                None => usize::MAX,
+            },
+            end: match end {
+                Some(end) => end,
+                None => usize::MAX,
+            },
+            len,
+        }),
+    }
 }
 
+#[cfg(test)]
+mod test_util;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,526 @@
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
+
+use crate::{BitOrder, BitSlice, BitStorage, BitStorageMut, ByteOrder, Error};
+
+impl<S: BitStorageMut, B: BitOrder, Endian: ByteOrder> BitSlice<S, B, Endian> {
+    /// Replaces every bit of `self` with `f` applied to it and the bit at the same index in
+    /// `other`, a word at a time.
+    ///
+    /// # Returns
+    /// [`Error::LengthMismatch`] if the slices have different lengths, in which case nothing is
+    /// written.
+    fn zip_bits<S2: BitStorage>(
+        &mut self,
+        other: &BitSlice<S2, B, Endian>,
+        f: impl Fn(u64, u64) -> u64,
+    ) -> Result<(), Error> {
+        if self.len() != other.len() {
+            return Err(Error::LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+        let len = self.len();
+        let mut i = 0;
+        if self.bit_order == other.bit_order
+            && self.byte_order == other.byte_order
+            && self.range.start.is_multiple_of(8)
+            && other.range.start.is_multiple_of(8)
+        {
+            // Both slices start on a byte boundary and share the bit and byte order, so whole
+            // bytes can be combined without reordering or shifting their bits.
+            let (first, other_first) = (self.range.start / 8, other.range.start / 8);
+            let (bit_order, layout, other_layout) = (self.bit_order, self.layout(), other.layout());
+            let whole = len / 8;
+            let mut k = 0;
+            while k < whole {
+                let count = (whole - k).min(8);
+                let a = layout.load(bit_order, self.storage(), first + k, count);
+                let b = other_layout.load(bit_order, other.storage(), other_first + k, count);
+                layout.store(bit_order, self.storage_mut(), first + k, count, f(a, b));
+                k += count;
+            }
+            i = whole * 8;
+        }
+        while i < len {
+            let n = (len - i).min(64);
+            let word = f(self.read_bits(i, n), other.read_bits(i, n));
+            self.write_bits(i, n, word);
+            i += n;
+        }
+        Ok(())
+    }
+    /// Replaces `self` with the bitwise AND of `self` and `other`, or returns
+    /// [`Error::LengthMismatch`] if the slices have different lengths.
+    ///
+    /// This is the non-panicking version of `&=`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{bits, BitSlice, Error};
+    /// let mut a: BitSlice<_> = bits![1, 1, 0, 0];
+    /// assert_eq!(a.try_and_assign(&bits![1, 0, 1, 0]), Ok(()));
+    /// assert!(a == bits![1, 0, 0, 0]);
+    /// assert_eq!(
+    ///     a.try_and_assign(&bits![1, 0, 1]),
+    ///     Err(Error::LengthMismatch { left: 4, right: 3 })
+    /// );
+    /// ```
+    pub fn try_and_assign<S2: BitStorage>(
+        &mut self,
+        other: &BitSlice<S2, B, Endian>,
+    ) -> Result<(), Error> {
+        self.zip_bits(other, |a, b| a & b)
+    }
+    /// Replaces `self` with the bitwise OR of `self` and `other`, or returns
+    /// [`Error::LengthMismatch`] if the slices have different lengths.
+    ///
+    /// This is the non-panicking version of `|=`.
+    pub fn try_or_assign<S2: BitStorage>(
+        &mut self,
+        other: &BitSlice<S2, B, Endian>,
+    ) -> Result<(), Error> {
+        self.zip_bits(other, |a, b| a | b)
+    }
+    /// Replaces `self` with the bitwise XOR of `self` and `other`, or returns
+    /// [`Error::LengthMismatch`] if the slices have different lengths.
+    ///
+    /// This is the non-panicking version of `^=`.
+    pub fn try_xor_assign<S2: BitStorage>(
+        &mut self,
+        other: &BitSlice<S2, B, Endian>,
+    ) -> Result<(), Error> {
+        self.zip_bits(other, |a, b| a ^ b)
+    }
+    /// Inverts every bit of the slice.
+    pub fn invert(&mut self) {
+        let len = self.len();
+        let mut i = 0;
+        if self.range.start.is_multiple_of(8) {
+            // Whole bytes can be inverted without reordering their bits.
+            let (first, whole) = (self.range.start / 8, len / 8);
+            let (bit_order, layout) = (self.bit_order, self.layout());
+            let mut k = 0;
+            while k < whole {
+                let count = (whole - k).min(8);
+                let word = layout.load(bit_order, self.storage(), first + k, count);
+                layout.store(bit_order, self.storage_mut(), first + k, count, !word);
+                k += count;
+            }
+            i = whole * 8;
+        }
+        while i < len {
+            let n = (len - i).min(64);
+            let word = !self.read_bits(i, n);
+            self.write_bits(i, n, word);
+            i += n;
+        }
+    }
+    /// Moves every bit `n` positions towards higher indices, filling the start with zeros.
+    fn shift_up(&mut self, n: usize) {
+        let len = self.len();
+        let n = n.min(len);
+        self.copy_bits_within(0..len - n, n);
+        self.fill_bits(0..n, false);
+    }
+    /// Moves every bit `n` positions towards lower indices, filling the end with zeros.
+    fn shift_down(&mut self, n: usize) {
+        let len = self.len();
+        let n = n.min(len);
+        self.copy_bits_within(n..len, 0);
+        self.fill_bits(len - n..len, false);
+    }
+    /// Reverses the order of the bits in `start..end`.
+    fn reverse_range(&mut self, mut start: usize, mut end: usize) {
+        while end - start >= 2 {
+            let n = ((end - start) / 2).min(64);
+            let lo = self.read_bits(start, n);
+            let hi = self.read_bits(end - n, n);
+            self.write_bits(start, n, hi.reverse_bits() >> (64 - n));
+            self.write_bits(end - n, n, lo.reverse_bits() >> (64 - n));
+            start += n;
+            end -= n;
+        }
+    }
+    /// Moves every bit `n` positions towards higher indices, wrapping around at the end.
+    fn rotate_up(&mut self, n: usize) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+        let n = n % len;
+        self.reverse_range(0, len);
+        self.reverse_range(0, n);
+        self.reverse_range(n, len);
+    }
+    /// Shifts the bits of the slice `n` positions towards its most significant bit, filling the
+    /// least significant bits with zeros.
+    ///
+    /// The bit order decides which end of the slice is the most significant one (see
+    /// [`BitSlice::get_field`]), so this is equivalent to `<<` on the integer value of the slice.
+    /// With [`Lsb0`](crate::Lsb0) the bits move to higher indices, with [`Msb0`](crate::Msb0) to
+    /// lower indices. Shifting by `len()` or more clears the slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Msb0};
+    /// let mut data = [0b1001_0110u8];
+    /// let mut bits: BitSlice<_, Msb0, LittleEndian> = data.as_mut().into();
+    /// bits.shift_left(2);
+    /// assert_eq!(data, [0b0101_1000]);
+    /// ```
+    pub fn shift_left(&mut self, n: usize) {
+        if self.bit_order.msb_first() {
+            self.shift_down(n)
+        } else {
+            self.shift_up(n)
+        }
+    }
+    /// Shifts the bits of the slice `n` positions towards its least significant bit, filling the
+    /// most significant bits with zeros.
+    ///
+    /// This is equivalent to `>>` on the integer value of the slice, see
+    /// [`BitSlice::shift_left`].
+    pub fn shift_right(&mut self, n: usize) {
+        if self.bit_order.msb_first() {
+            self.shift_up(n)
+        } else {
+            self.shift_down(n)
+        }
+    }
+    /// Rotates the bits of the slice `n` positions towards its most significant bit.
+    ///
+    /// This is equivalent to `rotate_left` on an integer with as many bits as the slice, see
+    /// [`BitSlice::shift_left`].
+    pub fn rotate_left(&mut self, n: usize) {
+        let len = self.len();
+        if self.bit_order.msb_first() && len > 0 {
+            self.rotate_up(len - n % len)
+        } else {
+            self.rotate_up(n)
+        }
+    }
+    /// Rotates the bits of the slice `n` positions towards its least significant bit.
+    ///
+    /// This is equivalent to `rotate_right` on an integer with as many bits as the slice, see
+    /// [`BitSlice::shift_left`].
+    pub fn rotate_right(&mut self, n: usize) {
+        let len = self.len();
+        if self.bit_order.msb_first() || len == 0 {
+            self.rotate_up(n)
+        } else {
+            self.rotate_up(len - n % len)
+        }
+    }
+}
+
+// Implementation of the binary bitwise operators and their assigning variants.
+//
+// The left operand is modified in place and returned, the right operand may use any storage.
+macro_rules! impl_bit_op {
+    ($($op:ident :: $f:ident, $op_assign:ident :: $f_assign:ident => $try_f:ident;)*) => {
+        $(
+            impl<S, S2, B, Endian> $op_assign<&BitSlice<S2, B, Endian>> for BitSlice<S, B, Endian>
+            where
+                S: BitStorageMut,
+                S2: BitStorage,
+                B: BitOrder,
+                Endian: ByteOrder,
+            {
+                /// # Panics
+                /// Panics if the slices have different lengths. See
+                #[doc = concat!("[`BitSlice::", stringify!($try_f), "`]")]
+                /// for a non-panicking version.
+                #[inline]
+                #[track_caller]
+                fn $f_assign(&mut self, rhs: &BitSlice<S2, B, Endian>) {
+                    self.$try_f(rhs).unwrap_or_else(|e| panic!("{e}"));
+                }
+            }
+            impl<S, S2, B, Endian> $op_assign<BitSlice<S2, B, Endian>> for BitSlice<S, B, Endian>
+            where
+                S: BitStorageMut,
+                S2: BitStorage,
+                B: BitOrder,
+                Endian: ByteOrder,
+            {
+                /// # Panics
+                /// Panics if the slices have different lengths.
+                #[inline(always)]
+                fn $f_assign(&mut self, rhs: BitSlice<S2, B, Endian>) {
+                    self.$f_assign(&rhs);
+                }
+            }
+            impl<S, S2, B, Endian> $op<&BitSlice<S2, B, Endian>> for BitSlice<S, B, Endian>
+            where
+                S: BitStorageMut,
+                S2: BitStorage,
+                B: BitOrder,
+                Endian: ByteOrder,
+            {
+                type Output = Self;
+                /// # Panics
+                /// Panics if the slices have different lengths.
+                #[inline(always)]
+                fn $f(mut self, rhs: &BitSlice<S2, B, Endian>) -> Self {
+                    self.$f_assign(rhs);
+                    self
+                }
+            }
+            impl<S, S2, B, Endian> $op<BitSlice<S2, B, Endian>> for BitSlice<S, B, Endian>
+            where
+                S: BitStorageMut,
+                S2: BitStorage,
+                B: BitOrder,
+                Endian: ByteOrder,
+            {
+                type Output = Self;
+                /// # Panics
+                /// Panics if the slices have different lengths.
+                #[inline(always)]
+                fn $f(mut self, rhs: BitSlice<S2, B, Endian>) -> Self {
+                    self.$f_assign(&rhs);
+                    self
+                }
+            }
+        )*
+    };
+}
+impl_bit_op! {
+    BitAnd::bitand, BitAndAssign::bitand_assign => try_and_assign;
+    BitOr::bitor, BitOrAssign::bitor_assign => try_or_assign;
+    BitXor::bitxor, BitXorAssign::bitxor_assign => try_xor_assign;
+}
+
+impl<S, B, Endian> Not for BitSlice<S, B, Endian>
+where
+    S: BitStorageMut,
+    B: BitOrder,
+    Endian: ByteOrder,
+{
+    type Output = Self;
+    #[inline(always)]
+    fn not(mut self) -> Self {
+        self.invert();
+        self
+    }
+}
+
+impl<S, B, Endian> ShlAssign<usize> for BitSlice<S, B, Endian>
+where
+    S: BitStorageMut,
+    B: BitOrder,
+    Endian: ByteOrder,
+{
+    /// See [`BitSlice::shift_left`].
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: usize) {
+        self.shift_left(rhs);
+    }
+}
+impl<S, B, Endian> ShrAssign<usize> for BitSlice<S, B, Endian>
+where
+    S: BitStorageMut,
+    B: BitOrder,
+    Endian: ByteOrder,
+{
+    /// See [`BitSlice::shift_right`].
+    #[inline(always)]
+    fn shr_assign(&mut self, rhs: usize) {
+        self.shift_right(rhs);
+    }
+}
+impl<S, B, Endian> Shl<usize> for BitSlice<S, B, Endian>
+where
+    S: BitStorageMut,
+    B: BitOrder,
+    Endian: ByteOrder,
+{
+    type Output = Self;
+    /// See [`BitSlice::shift_left`].
+    #[inline(always)]
+    fn shl(mut self, rhs: usize) -> Self {
+        self.shift_left(rhs);
+        self
+    }
+}
+impl<S, B, Endian> Shr<usize> for BitSlice<S, B, Endian>
+where
+    S: BitStorageMut,
+    B: BitOrder,
+    Endian: ByteOrder,
+{
+    type Output = Self;
+    /// See [`BitSlice::shift_right`].
+    #[inline(always)]
+    fn shr(mut self, rhs: usize) -> Self {
+        self.shift_right(rhs);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use crate::test_util::for_each_order;
+    use alloc::vec::Vec;
+
+    use crate::{bits, BigEndian, BitOrder, BitSlice, ByteOrder, DynBitOrder, DynEndian, Msb0};
+
+    /// Checks all operators against a per-bit reference for aligned and unaligned slices.
+    fn check_ops<B: BitOrder + Default, E: ByteOrder + Default>() {
+        let a_bytes: Vec<u8> = (0..20u32).map(|i| (i * 91 + 7) as u8).collect();
+        let b_bytes: Vec<u8> = (0..20u32).map(|i| (i * 29 + 3) as u8).collect();
+        let a: BitSlice<&[u8], B, E> = a_bytes.as_slice().into();
+        let b: BitSlice<&[u8], B, E> = b_bytes.as_slice().into();
+        for (a_start, b_start) in [(0, 0), (8, 16), (3, 0), (0, 5), (7, 9)] {
+            let len = 150 - a_start.max(b_start);
+            let range = a_start..a_start + len;
+            let x = a.slice(range.clone());
+            let y = b.slice(b_start..b_start + len);
+            let mut storage = a_bytes.clone();
+            let mut target = BitSlice {
+                bytes: storage.as_mut_slice(),
+                range: range.clone(),
+                bit_order: B::default(),
+                byte_order: E::default(),
+            };
+            target &= &y;
+            target |= &x;
+            target ^= &y;
+            let expected: Vec<bool> = x
+                .iter()
+                .zip(y.iter())
+                .map(|(p, q)| ((p & q) | p) ^ q)
+                .collect();
+            assert!(target == expected);
+            target.invert();
+            let expected: Vec<bool> = expected.iter().map(|p| !p).collect();
+            assert!(target == expected);
+
+            // Bits outside of the slice are not modified.
+            let result: BitSlice<&[u8], B, E> = storage.as_slice().into();
+            assert!(result.slice(..range.start) == a.slice(..range.start));
+            assert!(result.slice(range.end..) == a.slice(range.end..));
+        }
+    }
+
+    #[test]
+    fn test_ops() {
+        for_each_order!(check_ops);
+    }
+
+    #[test]
+    fn test_ops_mixed_orders() {
+        // Slices whose orders only differ at runtime are combined bit by bit.
+        let a_bytes: Vec<u8> = (0..20u32).map(|i| (i * 91 + 7) as u8).collect();
+        let b_bytes: Vec<u8> = (0..20u32).map(|i| (i * 29 + 3) as u8).collect();
+        for (x_order, y_order) in [
+            (DynBitOrder::Lsb0, DynBitOrder::Msb0),
+            (DynBitOrder::Msb0, DynBitOrder::Lsb0),
+        ] {
+            for (x_endian, y_endian) in [
+                (DynEndian::LittleEndian, DynEndian::LittleEndian),
+                (DynEndian::LittleEndian, DynEndian::BigEndian),
+            ] {
+                let mut storage = a_bytes.clone();
+                let original = BitSlice::new_with_order(a_bytes.as_slice(), 160, x_order, x_endian);
+                let mut x =
+                    BitSlice::new_with_order(storage.as_mut_slice(), 160, x_order, x_endian);
+                let y = BitSlice::new_with_order(b_bytes.as_slice(), 160, y_order, y_endian);
+                x &= &y;
+                let expected: Vec<bool> =
+                    original.iter().zip(y.iter()).map(|(p, q)| p & q).collect();
+                assert!(x == expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_operators() {
+        let a: BitSlice<_> = bits![1, 1, 0, 0, 1];
+        let b: BitSlice<_> = bits![1, 0, 1, 0, 1];
+        assert!((a.clone() & &b) == bits![1, 0, 0, 0, 1]);
+        assert!((a.clone() | &b) == bits![1, 1, 1, 0, 1]);
+        assert!((a.clone() ^ b.clone()) == bits![0, 1, 1, 0, 0]);
+        assert!(!a == bits![0, 0, 1, 1, 0]);
+    }
+
+    /// Checks shifts and rotations against the same operations on the integer value of a field.
+    fn check_shifts<B: BitOrder + Default, E: ByteOrder + Default>() {
+        let original: Vec<u8> = (0..20u32).map(|i| (i * 91 + 7) as u8).collect();
+        let full: BitSlice<&[u8], B, E> = original.as_slice().into();
+        for (start, len) in [(0, 128), (3, 100), (9, 64), (5, 1), (0, 0), (1, 127)] {
+            let range = start..start + len;
+            let value = full.get_field::<u128>(range.clone()).unwrap();
+            let mask = if len == 128 {
+                u128::MAX
+            } else {
+                (1 << len) - 1
+            };
+            for n in [0, 1, 7, 8, 63, 64, 65, 99, 127, 128, 300] {
+                let mut storage = original.clone();
+                let mut target = BitSlice {
+                    bytes: storage.as_mut_slice(),
+                    range: range.clone(),
+                    bit_order: B::default(),
+                    byte_order: E::default(),
+                };
+                let check = |target: &BitSlice<&mut [u8], B, E>, expected: u128| {
+                    assert_eq!(target.get_field::<u128>(..), Ok(expected & mask));
+                };
+                let shifted = if n >= 128 { 0 } else { value << n };
+                target.shift_left(n);
+                check(&target, shifted);
+                target.shift_right(n);
+                check(&target, if n >= 128 { 0 } else { (shifted & mask) >> n });
+
+                target.set_field(.., value).unwrap();
+                let rotated = |n: usize| match len {
+                    0 => 0,
+                    _ => {
+                        let n = n % len;
+                        (value << n) | value.checked_shr((len - n) as u32).unwrap_or(0)
+                    }
+                };
+                target.rotate_left(n);
+                check(&target, rotated(n));
+                target.set_field(.., value).unwrap();
+                target.rotate_right(n);
+                check(&target, rotated(len - n % len.max(1)));
+
+                // Bits outside of the slice are not modified.
+                let result: BitSlice<&[u8], B, E> = storage.as_slice().into();
+                assert!(result.slice(..range.start) == full.slice(..range.start));
+                assert!(result.slice(range.end..) == full.slice(range.end..));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shifts() {
+        for_each_order!(check_shifts);
+    }
+
+    #[test]
+    fn test_shift_operators() {
+        let a: BitSlice<_> = bits![1, 1, 0, 0, 1];
+        assert!((a.clone() << 2) == bits![0, 0, 1, 1, 0]);
+        assert!((a.clone() >> 1) == bits![1, 0, 0, 1, 0]);
+        let mut b: BitSlice<_, Msb0, BigEndian> = bits![1, 1, 0, 0, 1];
+        b <<= 1;
+        assert!(b == bits![1, 0, 0, 1, 0]);
+        b >>= 3;
+        assert!(b == bits![0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "BitSlice lengths differ: 5 != 4")]
+    fn test_length_mismatch() {
+        let mut a: BitSlice<_> = bits![1, 1, 0, 0, 1];
+        a &= bits![1, 0, 1, 0];
+    }
+}
@@ -1,9 +1,11 @@
+use core::marker::PhantomData;
+
 mod private {
     pub trait Sealed {}
 }
 
 /// The `BitOrder` trait defines behavior for bit ordering (e.g., MSB-first, LSB-first).
-pub trait BitOrder: Copy + private::Sealed {
+pub trait BitOrder: Copy + PartialEq + private::Sealed {
     /// Finds the position of a bit within a byte sequence.
     ///
     /// # Arguments
@@ -15,6 +17,16 @@ pub trait BitOrder: Copy + private::Sealed {
     /// A tuple `(usize, usize)` where the first element is the byte index and
     /// the second element is the bit index within that byte.
     fn find_bit(self, endian: impl ByteOrder, n: usize, num_bits: usize) -> (usize, usize);
+    /// Returns `true` if the first bit of a multi-bit value is its most significant bit.
+    ///
+    /// This decides how fields are read and written, e.g. by
+    /// [`BitSlice::get_field`](crate::BitSlice::get_field).
+    fn msb_first(self) -> bool;
+    /// Converts every byte of `word` between its storage layout and [`Lsb0`] layout.
+    ///
+    /// The conversion is its own inverse, so it is used for both loading and storing.
+    #[doc(hidden)]
+    fn reorder(self, word: u64) -> u64;
 }
 
 /// Represents most significant bit first ordering.
@@ -26,11 +38,14 @@ pub struct Msb0;
 pub struct Lsb0;
 
 /// A dynamic bit order that can be either MSB0 or LSB0.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// It defaults to [`DynBitOrder::Lsb0`], like the bit order of [`BitSlice`](crate::BitSlice).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum DynBitOrder {
     /// Represents most significant bit first ordering.
     Msb0,
     /// Represents least significant bit first ordering.
+    #[default]
     Lsb0,
 }
 // Implementations of the `Sealed` trait for the bit order types.
@@ -41,8 +56,18 @@ impl private::Sealed for DynBitOrder {}
 impl BitOrder for Msb0 {
     #[inline(always)]
     fn find_bit(self, endian: impl ByteOrder, n: usize, num_bits: usize) -> (usize, usize) {
-        let (byte, bit) = Lsb0::find_bit(Lsb0, endian, n, num_bits);
-        (byte, 7 - bit)
+        // Bit `n` is where `Lsb0` puts the bit mirrored within its word.
+        let width = 8 * endian.word_bytes();
+        let offset = n % width;
+        Lsb0::find_bit(Lsb0, endian, n - offset + width - 1 - offset, num_bits)
+    }
+    #[inline(always)]
+    fn msb_first(self) -> bool {
+        true
+    }
+    #[inline(always)]
+    fn reorder(self, word: u64) -> u64 {
+        word.reverse_bits().swap_bytes()
     }
 }
 impl BitOrder for Lsb0 {
@@ -52,6 +77,14 @@ impl BitOrder for Lsb0 {
         let bit = n % 8;
         (byte, bit)
     }
+    #[inline(always)]
+    fn msb_first(self) -> bool {
+        false
+    }
+    #[inline(always)]
+    fn reorder(self, word: u64) -> u64 {
+        word
+    }
 }
 impl BitOrder for DynBitOrder {
     #[inline]
@@ -61,6 +94,17 @@ impl BitOrder for DynBitOrder {
             DynBitOrder::Lsb0 => Lsb0::find_bit(Lsb0, endian, n, num_bits),
         }
     }
+    #[inline(always)]
+    fn msb_first(self) -> bool {
+        self == DynBitOrder::Msb0
+    }
+    #[inline(always)]
+    fn reorder(self, word: u64) -> u64 {
+        match self {
+            DynBitOrder::Msb0 => Msb0::reorder(Msb0, word),
+            DynBitOrder::Lsb0 => Lsb0::reorder(Lsb0, word),
+        }
+    }
 }
 
 impl PartialEq<Lsb0> for DynBitOrder {
@@ -101,7 +145,7 @@ impl PartialEq<Lsb0> for Msb0 {
 }
 
 /// The `ByteOrder` trait defines behavior for byte ordering.
-pub trait ByteOrder: Copy + private::Sealed {
+pub trait ByteOrder: Copy + PartialEq + private::Sealed {
     /// Finds the byte index for a given bit index.
     ///
     /// # Arguments
@@ -112,6 +156,38 @@ pub trait ByteOrder: Copy + private::Sealed {
     /// The byte index corresponding to the provided bit index.
     fn find_byte(self, bit_no: usize, num_bits: usize) -> usize;
     fn is_native(self) -> bool;
+    /// The number of bytes of a storage word, within which [`Msb0`] numbers the bits from the
+    /// most significant one. The storage must consist of whole words.
+    #[doc(hidden)]
+    const WORD_BYTES: usize;
+    /// Returns [`ByteOrder::WORD_BYTES`].
+    #[doc(hidden)]
+    #[inline(always)]
+    fn word_bytes(self) -> usize {
+        Self::WORD_BYTES
+    }
+    /// Returns `true` if the first bits are stored at the end of the storage, so that it grows
+    /// and splits at its start.
+    #[doc(hidden)]
+    fn starts_at_end(self) -> bool;
+    /// Loads `count` (at most 8) consecutive bytes starting at byte `first` into a word.
+    ///
+    /// Byte `first` ends up in the least significant byte of the result. Bytes are numbered by
+    /// the bits they hold, byte `i` holding bits `8 * i..8 * i + 8` in `bit_order`.
+    #[doc(hidden)]
+    fn load(self, bit_order: impl BitOrder, bytes: &[u8], first: usize, count: usize) -> u64;
+    /// Stores the `count` (at most 8) least significant bytes of `word` starting at byte `first`.
+    ///
+    /// This is the inverse of [`ByteOrder::load`].
+    #[doc(hidden)]
+    fn store(
+        self,
+        bit_order: impl BitOrder,
+        bytes: &mut [u8],
+        first: usize,
+        count: usize,
+        word: u64,
+    );
 }
 /// Represents little endian byte ordering.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -122,8 +198,12 @@ pub struct LittleEndian;
 pub struct BigEndian;
 
 /// A dynamic endian type that can be either `LittleEndian` or `BigEndian`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// It defaults to [`DynEndian::LittleEndian`], like the byte order of
+/// [`BitSlice`](crate::BitSlice).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum DynEndian {
+    #[default]
     LittleEndian,
     BigEndian,
 }
@@ -135,6 +215,7 @@ impl private::Sealed for DynEndian {}
 
 // Implementations of `ByteOrder` trait for each endian type.
 impl ByteOrder for BigEndian {
+    const WORD_BYTES: usize = 1;
     #[inline(always)]
     fn find_byte(self, bit_no: usize, num_bits: usize) -> usize {
         assert!(bit_no < num_bits);
@@ -148,8 +229,25 @@ impl ByteOrder for BigEndian {
         #[cfg(target_endian = "big")]
         return true;
     }
+    #[inline(always)]
+    fn starts_at_end(self) -> bool {
+        true
+    }
+    #[inline]
+    fn load(self, _: impl BitOrder, bytes: &[u8], first: usize, count: usize) -> u64 {
+        let end = bytes.len() - first;
+        let mut buf = [0; 8];
+        buf[8 - count..].copy_from_slice(&bytes[end - count..end]);
+        u64::from_be_bytes(buf)
+    }
+    #[inline]
+    fn store(self, _: impl BitOrder, bytes: &mut [u8], first: usize, count: usize, word: u64) {
+        let end = bytes.len() - first;
+        bytes[end - count..end].copy_from_slice(&word.to_be_bytes()[8 - count..]);
+    }
 }
 impl ByteOrder for LittleEndian {
+    const WORD_BYTES: usize = 1;
     #[inline(always)]
     fn find_byte(self, bit_no: usize, _num_bits: usize) -> usize {
         bit_no / 8
@@ -161,8 +259,23 @@ impl ByteOrder for LittleEndian {
         #[cfg(target_endian = "big")]
         return false;
     }
+    #[inline(always)]
+    fn starts_at_end(self) -> bool {
+        false
+    }
+    #[inline]
+    fn load(self, _: impl BitOrder, bytes: &[u8], first: usize, count: usize) -> u64 {
+        let mut buf = [0; 8];
+        buf[..count].copy_from_slice(&bytes[first..first + count]);
+        u64::from_le_bytes(buf)
+    }
+    #[inline]
+    fn store(self, _: impl BitOrder, bytes: &mut [u8], first: usize, count: usize, word: u64) {
+        bytes[first..first + count].copy_from_slice(&word.to_le_bytes()[..count]);
+    }
 }
 impl ByteOrder for DynEndian {
+    const WORD_BYTES: usize = 1;
     #[inline(always)]
     fn find_byte(self, bit_no: usize, num_bits: usize) -> usize {
         match self {
@@ -177,6 +290,197 @@ impl ByteOrder for DynEndian {
         #[cfg(target_endian = "big")]
         return self == DynEndian::BigEndian;
     }
+    #[inline(always)]
+    fn starts_at_end(self) -> bool {
+        self == DynEndian::BigEndian
+    }
+    #[inline(always)]
+    fn load(self, bit_order: impl BitOrder, bytes: &[u8], first: usize, count: usize) -> u64 {
+        match self {
+            DynEndian::BigEndian => BigEndian.load(bit_order, bytes, first, count),
+            DynEndian::LittleEndian => LittleEndian.load(bit_order, bytes, first, count),
+        }
+    }
+    #[inline(always)]
+    fn store(
+        self,
+        bit_order: impl BitOrder,
+        bytes: &mut [u8],
+        first: usize,
+        count: usize,
+        word: u64,
+    ) {
+        match self {
+            DynEndian::BigEndian => BigEndian.store(bit_order, bytes, first, count, word),
+            DynEndian::LittleEndian => LittleEndian.store(bit_order, bytes, first, count, word),
+        }
+    }
+}
+
+/// The `BitStore` trait is implemented by the unsigned integers that can serve as the words of
+/// a storage, see [`Words`].
+pub trait BitStore: Copy + Default + Eq + core::fmt::Debug + private::Sealed {
+    /// The number of bits in a word.
+    const BITS: usize;
+}
+macro_rules! impl_bit_store {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl BitStore for $t {
+                const BITS: usize = <$t>::BITS as usize;
+            }
+        )*
+    };
+}
+impl_bit_store!(u8, u16, u32, u64);
+
+/// Represents a storage of `W` words in native byte order, such as a `[u32]` of
+/// memory-mapped registers.
+///
+/// The bits are numbered word by word, and within a word [`Lsb0`] numbers them from its least
+/// significant bit and [`Msb0`] from its most significant one. So with [`Lsb0`] bit `n` has the
+/// value `1 << (n % W::BITS)` in word `n / W::BITS`, and with [`Msb0`] it has the value
+/// `1 << (W::BITS - 1 - n % W::BITS)`.
+///
+/// This is the layout a [`BitSlice`](crate::BitSlice) over a storage of `W` words, such as
+/// `[u32; N]`, has with [`LittleEndian`], see [`BitStorage`](crate::BitStorage). `Words` gives
+/// storage of bytes the same layout, e.g. a `BitVec` or the memory of a device. The storage must
+/// consist of whole words, which the constructors of [`BitSlice`](crate::BitSlice) check, and
+/// `BitVec` and [`BitWriter`](crate::BitWriter) grow their storage a word at a time.
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BitSlice, LittleEndian, Msb0, Words};
+/// let regs = [0x8000_0000u32, 0x0a00_0000];
+/// let bytes: Vec<u8> = regs.iter().flat_map(|reg| reg.to_ne_bytes()).collect();
+/// let bits: BitSlice<_, Msb0, Words<u32>> = bytes.as_slice().into();
+/// let words: BitSlice<_, Msb0, LittleEndian> = BitSlice::from_words(&regs);
+/// assert!(bits.iter().eq(words.iter()));
+/// assert_eq!(bits.get_field::<u8>(36..40).unwrap(), 0xa);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Words<W>(PhantomData<W>);
+
+impl<W> private::Sealed for Words<W> {}
+impl<W: BitStore> ByteOrder for Words<W> {
+    const WORD_BYTES: usize = W::BITS / 8;
+    /// Finds the byte holding bit `bit_no` in [`Lsb0`] order.
+    #[inline(always)]
+    fn find_byte(self, bit_no: usize, _num_bits: usize) -> usize {
+        let (word, byte) = (bit_no / W::BITS, bit_no % W::BITS / 8);
+        #[cfg(target_endian = "little")]
+        return word * (W::BITS / 8) + byte;
+        #[cfg(target_endian = "big")]
+        return word * (W::BITS / 8) + W::BITS / 8 - 1 - byte;
+    }
+    #[inline(always)]
+    fn is_native(self) -> bool {
+        true
+    }
+    #[inline(always)]
+    fn starts_at_end(self) -> bool {
+        false
+    }
+    #[inline]
+    fn load(self, bit_order: impl BitOrder, bytes: &[u8], first: usize, count: usize) -> u64 {
+        let mut word = 0;
+        for i in 0..count {
+            let (byte, _) = bit_order.find_bit(self, (first + i) * 8, bytes.len() * 8);
+            word |= (bytes[byte] as u64) << (8 * i);
+        }
+        word
+    }
+    #[inline]
+    fn store(
+        self,
+        bit_order: impl BitOrder,
+        bytes: &mut [u8],
+        first: usize,
+        count: usize,
+        word: u64,
+    ) {
+        for i in 0..count {
+            let (byte, _) = bit_order.find_bit(self, (first + i) * 8, bytes.len() * 8);
+            bytes[byte] = (word >> (8 * i)) as u8;
+        }
+    }
+}
+
+/// The byte order of a storage of `W` words with the byte order `Endian`, see
+/// [`BitStorage`](crate::BitStorage).
+///
+/// Its words are the larger of `W` and the words of `Endian`. Words that are larger than those
+/// of `Endian` are laid out like [`Words`] and ordered like the bytes of `Endian`, so with
+/// [`BigEndian`] the first bits are in the last word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Layout<Endian, W>(Endian, PhantomData<W>);
+
+impl<Endian: ByteOrder, W: BitStore> Layout<Endian, W> {
+    /// `true` if the words of the storage are larger than those of `Endian`.
+    const WIDE: bool = W::BITS / 8 > Endian::WORD_BYTES;
+
+    #[inline(always)]
+    pub(crate) fn new(endian: Endian) -> Self {
+        Self(endian, PhantomData)
+    }
+}
+impl<Endian, W> private::Sealed for Layout<Endian, W> {}
+impl<Endian: ByteOrder, W: BitStore> ByteOrder for Layout<Endian, W> {
+    const WORD_BYTES: usize = if Self::WIDE {
+        W::BITS / 8
+    } else {
+        Endian::WORD_BYTES
+    };
+    #[inline(always)]
+    fn find_byte(self, bit_no: usize, num_bits: usize) -> usize {
+        if !Self::WIDE {
+            return self.0.find_byte(bit_no, num_bits);
+        }
+        let word = if self.0.starts_at_end() {
+            num_bits / W::BITS - 1 - bit_no / W::BITS
+        } else {
+            bit_no / W::BITS
+        };
+        Words::<W>::default().find_byte(word * W::BITS + bit_no % W::BITS, num_bits)
+    }
+    #[inline(always)]
+    fn is_native(self) -> bool {
+        Self::WIDE || self.0.is_native()
+    }
+    #[inline(always)]
+    fn starts_at_end(self) -> bool {
+        self.0.starts_at_end()
+    }
+    #[inline]
+    fn load(self, bit_order: impl BitOrder, bytes: &[u8], first: usize, count: usize) -> u64 {
+        if !Self::WIDE {
+            return self.0.load(bit_order, bytes, first, count);
+        }
+        let mut word = 0;
+        for i in 0..count {
+            let (byte, _) = bit_order.find_bit(self, (first + i) * 8, bytes.len() * 8);
+            word |= (bytes[byte] as u64) << (8 * i);
+        }
+        word
+    }
+    #[inline]
+    fn store(
+        self,
+        bit_order: impl BitOrder,
+        bytes: &mut [u8],
+        first: usize,
+        count: usize,
+        word: u64,
+    ) {
+        if !Self::WIDE {
+            return self.0.store(bit_order, bytes, first, count, word);
+        }
+        for i in 0..count {
+            let (byte, _) = bit_order.find_bit(self, (first + i) * 8, bytes.len() * 8);
+            bytes[byte] = (word >> (8 * i)) as u8;
+        }
+    }
 }
 
 impl PartialEq<LittleEndian> for DynEndian {
@@ -216,6 +520,60 @@ impl PartialEq<BigEndian> for LittleEndian {
     }
 }
 
+/// The `ConstBitOrder` trait is implemented by the bit orders that are fixed at compile time.
+///
+/// It allows [`BitSlice`](crate::BitSlice)s to be built in `const` contexts, e.g. by
+/// [`BitSlice::from_bools`](crate::BitSlice::from_bools) and the [`bits!`](crate::bits) macro.
+pub trait ConstBitOrder: BitOrder {
+    /// The value of the bit order.
+    const ORDER: Self;
+    /// The result of [`BitOrder::msb_first`].
+    const MSB_FIRST: bool;
+}
+impl ConstBitOrder for Msb0 {
+    const ORDER: Self = Msb0;
+    const MSB_FIRST: bool = true;
+}
+impl ConstBitOrder for Lsb0 {
+    const ORDER: Self = Lsb0;
+    const MSB_FIRST: bool = false;
+}
+
+/// The `ConstByteOrder` trait is implemented by the byte orders that are fixed at compile time.
+///
+/// See [`ConstBitOrder`].
+pub trait ConstByteOrder: ByteOrder {
+    /// The value of the byte order.
+    const ORDER: Self;
+    /// `true` if the first bits are stored in the last byte.
+    const BIG_ENDIAN: bool;
+}
+impl ConstByteOrder for LittleEndian {
+    const ORDER: Self = LittleEndian;
+    const BIG_ENDIAN: bool = false;
+}
+impl ConstByteOrder for BigEndian {
+    const ORDER: Self = BigEndian;
+    const BIG_ENDIAN: bool = true;
+}
+
+/// Finds the position of bit `n` in a storage of `num_bytes` bytes in a `const` context.
+///
+/// This is the `const` equivalent of [`BitOrder::find_bit`].
+#[inline(always)]
+pub(crate) const fn const_find_bit<B: ConstBitOrder, Endian: ConstByteOrder>(
+    n: usize,
+    num_bytes: usize,
+) -> (usize, usize) {
+    let byte = if Endian::BIG_ENDIAN {
+        num_bytes - n / 8 - 1
+    } else {
+        n / 8
+    };
+    let bit = if B::MSB_FIRST { 7 - n % 8 } else { n % 8 };
+    (byte, bit)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,4 +611,80 @@ mod tests {
         assert_eq!(DynBitOrder::Msb0.find_bit(LittleEndian, 10, 32), (1, 5));
         assert_eq!(DynBitOrder::Lsb0.find_bit(BigEndian, 10, 32), (2, 2));
     }
+
+    #[test]
+    fn test_reorder() {
+        assert_eq!(Lsb0.reorder(0x0180), 0x0180);
+        assert_eq!(Msb0.reorder(0x0180), 0x8001);
+        assert_eq!(Msb0.reorder(Msb0.reorder(0x1234_5678)), 0x1234_5678);
+    }
+
+    #[test]
+    fn test_const_find_bit() {
+        for n in 0..32 {
+            assert_eq!(
+                const_find_bit::<Msb0, BigEndian>(n, 4),
+                Msb0.find_bit(BigEndian, n, 32)
+            );
+            assert_eq!(
+                const_find_bit::<Lsb0, LittleEndian>(n, 4),
+                Lsb0.find_bit(LittleEndian, n, 32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_store() {
+        let mut bytes = [1u8, 2, 3, 4];
+        assert_eq!(LittleEndian.load(Lsb0, &bytes, 1, 2), 0x0302);
+        assert_eq!(BigEndian.load(Lsb0, &bytes, 1, 2), 0x0203);
+        BigEndian.store(Lsb0, &mut bytes, 0, 2, 0xaabb);
+        assert_eq!(bytes, [1, 2, 0xaa, 0xbb]);
+        LittleEndian.store(Lsb0, &mut bytes, 0, 2, 0xaabb);
+        assert_eq!(bytes, [0xbb, 0xaa, 0xaa, 0xbb]);
+    }
+
+    #[test]
+    fn test_words_find_bit() {
+        let words = Words::<u32>::default();
+        for n in 0..64 {
+            let (word, bit) = (n / 32, n % 32);
+            let bytes = 0x1u32 << bit;
+            let (byte, shift) = Lsb0.find_bit(words, n, 64);
+            assert_eq!(
+                (byte / 4, bytes.to_ne_bytes()[byte % 4]),
+                (word, 1 << shift)
+            );
+            let bytes = 0x8000_0000u32 >> bit;
+            let (byte, shift) = Msb0.find_bit(words, n, 64);
+            assert_eq!(
+                (byte / 4, bytes.to_ne_bytes()[byte % 4]),
+                (word, 1 << shift)
+            );
+        }
+        // Words of one byte are laid out like `LittleEndian`.
+        for n in 0..16 {
+            assert_eq!(
+                Msb0.find_bit(Words::<u8>::default(), n, 16),
+                Msb0.find_bit(LittleEndian, n, 16)
+            );
+        }
+    }
+
+    #[test]
+    fn test_words_load_store() {
+        let mut bytes = [0u8; 8];
+        let words = Words::<u16>::default();
+        words.store(Msb0, &mut bytes, 1, 4, 0x4433_2211);
+        // Bytes are numbered from the most significant byte of each word.
+        let expected = [0x0011u16, 0x2233, 0x4400, 0x0000];
+        for (i, word) in expected.iter().enumerate() {
+            assert_eq!(bytes[2 * i..2 * i + 2], word.to_ne_bytes());
+        }
+        assert_eq!(words.load(Msb0, &bytes, 1, 4), 0x4433_2211);
+        assert_eq!(
+            words.load(Lsb0, &bytes, 0, 2),
+            u16::from_ne_bytes([bytes[0], bytes[1]]) as u64
+        );
+    }
 }
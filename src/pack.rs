@@ -0,0 +1,124 @@
+use crate::{BitOrder, BitSlice, BitStorage, BitStorageMut, ByteOrder, Error, LittleEndian, Lsb0};
+
+/// The `BitPack` trait is implemented by types that can be packed into and unpacked from the
+/// bits of a [`BitSlice`], such as registers and packet headers.
+///
+/// Integers are packed like [`BitSlice::set_field`] writes them into the whole slice, signed
+/// integers in two's complement, and `bool` as a single bit.
+///
+/// # Deriving
+/// With the `derive` feature, `#[derive(BitPack)]` implements the trait for structs whose fields
+/// are marked with the bits they occupy, `#[bits(3..17)]`, `#[bits(3..=16)]` or `#[bits(5)]` for
+/// a single bit. Every field type must implement [`BitPack`] itself, so structs can be nested.
+/// Fieldless enums are packed as their discriminant into the whole slice, and unpacking a value
+/// that is not a discriminant returns [`Error::ConversionError`]. Negative discriminants are
+/// rejected at compile time.
+///
+/// The type can be configured with the `#[bitpack(..)]` attribute:
+/// - `order = Msb0` or `order = Lsb0` only implements the trait for that bit order, instead of
+///   for all of them.
+/// - `endian = BigEndian` or `endian = LittleEndian` does the same for the byte order.
+/// - `reserved = 17..20` marks bits that are cleared by [`BitPack::pack`] and ignored by
+///   [`BitPack::unpack`]. It can be given more than once.
+///
+/// Ranges are checked at compile time not to overlap.
+///
+#[cfg_attr(feature = "derive", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// #[derive(bitslicer::BitPack)]
+/// struct Overlapping {
+///     #[bits(0..4)]
+///     low: u8,
+///     #[bits(3..8)]
+///     high: u8,
+/// }
+/// ```
+///
+#[cfg_attr(feature = "derive", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// #[derive(bitslicer::BitPack)]
+/// #[repr(i8)]
+/// enum Offset {
+///     Back = -1,
+///     Forward = 1,
+/// }
+/// ```
+///
+/// # Examples
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use bitslicer::{BigEndian, BitPack, BitSlice, Msb0};
+///
+/// #[derive(BitPack, Debug, PartialEq)]
+/// enum Mode {
+///     Idle = 0,
+///     Run = 5,
+/// }
+///
+/// #[derive(BitPack, Debug, PartialEq)]
+/// #[bitpack(order = Msb0, endian = BigEndian, reserved = 4..8)]
+/// struct Control {
+///     #[bits(0)]
+///     enable: bool,
+///     #[bits(1..4)]
+///     mode: Mode,
+///     #[bits(8..16)]
+///     offset: i8,
+/// }
+///
+/// let control = Control { enable: true, mode: Mode::Run, offset: -2 };
+/// let mut data = [0xffu8; 2];
+/// control.pack(&mut BitSlice::from(data.as_mut())).unwrap();
+/// assert_eq!(data, [0xfe, 0b1101_0000]);
+/// assert_eq!(Control::unpack(&BitSlice::from(data.as_ref())), Ok(control));
+/// ```
+pub trait BitPack<B: BitOrder = Lsb0, Endian: ByteOrder = LittleEndian>: Sized {
+    /// Packs the value into `bits`.
+    ///
+    /// # Returns
+    /// An error if `bits` is too short or a value does not fit into its bits, in which case
+    /// the bits may be partly written.
+    fn pack<S: BitStorageMut>(&self, bits: &mut BitSlice<S, B, Endian>) -> Result<(), Error>;
+    /// Unpacks a value from `bits`.
+    ///
+    /// # Returns
+    /// An error if `bits` is too short or a value does not fit into its type.
+    fn unpack<S: BitStorage>(bits: &BitSlice<S, B, Endian>) -> Result<Self, Error>;
+}
+
+macro_rules! impl_bit_pack {
+    ($get:ident, $set:ident: $($t:ty),*) => {
+        $(
+            impl<B: BitOrder, Endian: ByteOrder> BitPack<B, Endian> for $t {
+                #[inline(always)]
+                fn pack<S: BitStorageMut>(
+                    &self,
+                    bits: &mut BitSlice<S, B, Endian>,
+                ) -> Result<(), Error> {
+                    bits.$set(.., *self)
+                }
+                #[inline(always)]
+                fn unpack<S: BitStorage>(bits: &BitSlice<S, B, Endian>) -> Result<Self, Error> {
+                    bits.$get(..)
+                }
+            }
+        )*
+    };
+}
+impl_bit_pack!(get_field, set_field: u8, u16, u32, u64, u128, usize);
+impl_bit_pack!(get_signed_field, set_signed_field: i8, i16, i32, i64, i128, isize);
+
+impl<B: BitOrder, Endian: ByteOrder> BitPack<B, Endian> for bool {
+    #[inline(always)]
+    fn pack<S: BitStorageMut>(&self, bits: &mut BitSlice<S, B, Endian>) -> Result<(), Error> {
+        bits.set_field(.., *self as u8)
+    }
+    #[inline(always)]
+    fn unpack<S: BitStorage>(bits: &BitSlice<S, B, Endian>) -> Result<Self, Error> {
+        match bits.get_field::<u8>(..)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
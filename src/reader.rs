@@ -0,0 +1,231 @@
+use crate::{
+    BitOrder, BitSlice, BitStorage, ByteOrder, Error, LittleEndian, Lsb0, Signed, Unsigned,
+};
+
+/// A cursor that reads bits, integers and sub-slices from a [`BitSlice`] one after another.
+///
+/// Integers are read like [`BitSlice::get_field`] reads them, so the bit order decides whether
+/// the first bit read is the most or the least significant bit. Bitstreams that are sent most
+/// significant bit first, such as H.264 or ASN.1 PER, are read with [`Msb0`](crate::Msb0) and
+/// [`LittleEndian`], so that the bytes are read in storage order.
+///
+/// Every read checks that enough bits are left and returns [`Error::EndOfData`] otherwise, in
+/// which case the position is not changed.
+///
+/// # Type Parameters
+/// - `S`: The underlying storage type, typically a byte slice.
+/// - `B`: The bit order, which dictates the order in which bits are read.
+/// - `Endian`: The byte order, which dictates the order in which bytes are read.
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BitReader, BitSlice, LittleEndian, Msb0};
+/// let data = [0b1011_1111u8, 0b1100_0000];
+/// let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+/// let mut reader = BitReader::new(bits);
+/// assert_eq!(reader.read_bit(), Ok(true));
+/// assert_eq!(reader.read::<u8>(3), Ok(0b011));
+/// assert_eq!(reader.read_signed::<i8>(6), Ok(-1));
+/// assert_eq!(reader.remaining(), 6);
+/// reader.align_to_byte().unwrap();
+/// assert!(reader.read::<u8>(8).is_err());
+/// ```
+pub struct BitReader<S, B = Lsb0, Endian = LittleEndian> {
+    pub(crate) slice: BitSlice<S, B, Endian>,
+    pub(crate) pos: usize,
+}
+
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> BitReader<S, B, Endian> {
+    /// Creates a new [BitReader] that starts reading at the first bit of `slice`.
+    #[inline(always)]
+    pub fn new(slice: BitSlice<S, B, Endian>) -> Self {
+        Self { slice, pos: 0 }
+    }
+    /// Returns the index of the next bit to be read.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+    /// Returns the total number of bits of the underlying [BitSlice].
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+    /// Returns `true` if the underlying [BitSlice] contains no bits.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+    /// Returns the number of bits that are left to read.
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.slice.len() - self.pos
+    }
+    /// Returns `true` if the position is a multiple of 8 bits from the start of the data.
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool {
+        self.pos.is_multiple_of(8)
+    }
+    /// Returns the underlying [BitSlice].
+    #[inline(always)]
+    pub fn into_inner(self) -> BitSlice<S, B, Endian> {
+        self.slice
+    }
+    /// Checks that `n` more bits can be read and returns the range they occupy.
+    pub(crate) fn take(&self, n: usize) -> Result<core::ops::Range<usize>, Error> {
+        if n > self.remaining() {
+            return Err(Error::EndOfData {
+                requested: n,
+                remaining: self.remaining(),
+            });
+        }
+        Ok(self.pos..self.pos + n)
+    }
+    /// Moves the position to bit `pos`.
+    ///
+    /// # Returns
+    /// [`Error::IndexOutOfBounds`] if `pos` is past the end of the data.
+    pub fn seek(&mut self, pos: usize) -> Result<(), Error> {
+        if pos > self.slice.len() {
+            return Err(Error::IndexOutOfBounds {
+                index: pos,
+                len: self.slice.len(),
+            });
+        }
+        self.pos = pos;
+        Ok(())
+    }
+    /// Skips the next `n` bits.
+    pub fn skip(&mut self, n: usize) -> Result<(), Error> {
+        self.pos = self.take(n)?.end;
+        Ok(())
+    }
+    /// Skips to the next multiple of 8 bits from the start of the data. Does nothing if the
+    /// position is already aligned.
+    pub fn align_to_byte(&mut self) -> Result<(), Error> {
+        self.skip(self.pos.next_multiple_of(8) - self.pos)
+    }
+    /// Reads the next bit.
+    pub fn read_bit(&mut self) -> Result<bool, Error> {
+        let bit = self.peek_bit()?;
+        self.pos += 1;
+        Ok(bit)
+    }
+    /// Returns the next bit without advancing the position.
+    pub fn peek_bit(&self) -> Result<bool, Error> {
+        let range = self.take(1)?;
+        Ok(self.slice.get_bit(range.start))
+    }
+    /// Reads the next `n` bits as an unsigned integer.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if fewer than `n` bits are left or
+    /// [`Error::ConversionError`] if the value does not fit into `T`.
+    pub fn read<T: Unsigned>(&mut self, n: usize) -> Result<T, Error> {
+        let value = self.peek(n)?;
+        self.pos += n;
+        Ok(value)
+    }
+    /// Reads the next `n` bits as an unsigned integer without advancing the position.
+    ///
+    /// See [`BitReader::read`].
+    pub fn peek<T: Unsigned>(&self, n: usize) -> Result<T, Error> {
+        self.slice.get_field(self.take(n)?)
+    }
+    /// Reads the next `n` bits as a two's complement signed integer.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if fewer than `n` bits are left or
+    /// [`Error::ConversionError`] if the value does not fit into `T`.
+    pub fn read_signed<T: Signed>(&mut self, n: usize) -> Result<T, Error> {
+        let value = self.peek_signed(n)?;
+        self.pos += n;
+        Ok(value)
+    }
+    /// Reads the next `n` bits as a two's complement signed integer without advancing the
+    /// position.
+    ///
+    /// See [`BitReader::read_signed`].
+    pub fn peek_signed<T: Signed>(&self, n: usize) -> Result<T, Error> {
+        self.slice.get_signed_field(self.take(n)?)
+    }
+    /// Reads the next `n` bits as a [BitSlice] that borrows the underlying storage.
+    pub fn read_slice(&mut self, n: usize) -> Result<BitSlice<&[S::Word], B, Endian>, Error> {
+        let range = self.take(n)?;
+        self.pos = range.end;
+        Ok(self.slice.slice(range))
+    }
+}
+
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> From<BitSlice<S, B, Endian>>
+    for BitReader<S, B, Endian>
+{
+    #[inline(always)]
+    fn from(slice: BitSlice<S, B, Endian>) -> Self {
+        Self::new(slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lsb0, Msb0};
+
+    #[test]
+    fn test_reads() {
+        let data = [0b1011_0010u8, 0x74, 0xff, 0x80];
+        let mut reader: BitReader<_, Msb0, LittleEndian> = BitSlice::from(data.as_ref()).into();
+        assert_eq!(reader.read_bit(), Ok(true));
+        assert_eq!(reader.peek::<u8>(3), Ok(0b011));
+        assert_eq!(reader.read::<u8>(3), Ok(0b011));
+        assert_eq!(reader.read::<u16>(12), Ok(0x274));
+        assert_eq!(reader.read_signed::<i8>(4), Ok(-1));
+        assert!(reader.read_slice(4).unwrap() == [true; 4]);
+        assert_eq!((reader.position(), reader.remaining()), (24, 8));
+        assert_eq!(reader.read::<u64>(8), Ok(0x80));
+
+        // With `Lsb0` the first bit read is the least significant bit of the value.
+        let data = [0x2eu8, 0x4d, 0xf8, 0x01];
+        let mut reader: BitReader<_, Lsb0, LittleEndian> = BitSlice::from(data.as_ref()).into();
+        assert_eq!(reader.read_bit(), Ok(false));
+        assert_eq!(reader.read::<u8>(3), Ok(0b111));
+        assert_eq!(reader.read::<u16>(12), Ok(0x4d2));
+        assert_eq!(reader.read_signed::<i8>(4), Ok(-8));
+        assert!(reader.read_slice(4).unwrap() == [true; 4]);
+        assert_eq!(reader.read::<u64>(8), Ok(0x01));
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn test_end_of_data() {
+        let data = [0xa5u8, 0x0f];
+        let mut reader: BitReader<_, Msb0, LittleEndian> = BitSlice::from(data.as_ref()).into();
+        reader.skip(5).unwrap();
+        assert!(!reader.is_aligned());
+        let end = Error::EndOfData {
+            requested: 12,
+            remaining: 11,
+        };
+        assert_eq!(reader.read::<u16>(12), Err(end));
+        assert_eq!(reader.peek_signed::<i16>(12), Err(end));
+        assert_eq!(reader.position(), 5);
+        reader.align_to_byte().unwrap();
+        assert_eq!(reader.read::<u8>(8), Ok(0x0f));
+        assert_eq!(
+            reader.read_bit(),
+            Err(Error::EndOfData {
+                requested: 1,
+                remaining: 0,
+            })
+        );
+        assert_eq!(reader.align_to_byte(), Ok(()));
+        assert_eq!(
+            reader.seek(17),
+            Err(Error::IndexOutOfBounds { index: 17, len: 16 })
+        );
+        reader.seek(4).unwrap();
+        assert_eq!(reader.read::<u8>(4), Ok(0x5));
+        assert_eq!(reader.read::<u8>(0), Ok(0));
+        assert_eq!(reader.into_inner().len(), 16);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{low_mask, BitOrder, BitSlice, BitStorage, ByteOrder};
+
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> BitSlice<S, B, Endian> {
+    /// Returns the number of bits that are set.
+    ///
+    /// The bits are counted a word at a time with the native popcount instruction.
+    pub fn count_ones(&self) -> usize {
+        let len = self.len();
+        let mut count = 0;
+        let mut i = 0;
+        if self.range.start.is_multiple_of(8) {
+            // The order of the bits within a word does not matter for counting them, so whole
+            // bytes are counted without reordering their bits.
+            let (first, whole) = (self.range.start / 8, len / 8);
+            let mut k = 0;
+            while k < whole {
+                let n = (whole - k).min(8);
+                let word = self
+                    .layout()
+                    .load(self.bit_order, self.storage(), first + k, n);
+                count += word.count_ones() as usize;
+                k += n;
+            }
+            i = whole * 8;
+        }
+        while i < len {
+            let n = (len - i).min(64);
+            count += self.read_bits(i, n).count_ones() as usize;
+            i += n;
+        }
+        count
+    }
+    /// Returns the number of bits that are not set.
+    #[inline(always)]
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+    /// Returns the index of the first bit equal to `value`.
+    pub(crate) fn find_first(&self, value: bool) -> Option<usize> {
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(64);
+            let mut word = self.read_bits(i, n);
+            if !value {
+                word = !word & low_mask(n);
+            }
+            if word != 0 {
+                return Some(i + word.trailing_zeros() as usize);
+            }
+            i += n;
+        }
+        None
+    }
+    /// Returns the index of the last bit equal to `value`.
+    pub(crate) fn find_last(&self, value: bool) -> Option<usize> {
+        let mut end = self.len();
+        while end > 0 {
+            let n = end.min(64);
+            let mut word = self.read_bits(end - n, n);
+            if !value {
+                word = !word & low_mask(n);
+            }
+            if word != 0 {
+                return Some(end - n + 63 - word.leading_zeros() as usize);
+            }
+            end -= n;
+        }
+        None
+    }
+    /// Returns the index of the first bit that is set, or `None` if no bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Msb0};
+    /// let data = [0b0000_0000u8, 0b0010_0100];
+    /// let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+    /// assert_eq!(bits.first_one(), Some(10));
+    /// assert_eq!(bits.last_one(), Some(13));
+    /// assert_eq!(bits.slice(..8).first_one(), None);
+    /// ```
+    #[inline(always)]
+    pub fn first_one(&self) -> Option<usize> {
+        self.find_first(true)
+    }
+    /// Returns the index of the first bit that is not set, or `None` if all bits are set.
+    #[inline(always)]
+    pub fn first_zero(&self) -> Option<usize> {
+        self.find_first(false)
+    }
+    /// Returns the index of the last bit that is set, or `None` if no bit is set.
+    #[inline(always)]
+    pub fn last_one(&self) -> Option<usize> {
+        self.find_last(true)
+    }
+    /// Returns the index of the last bit that is not set, or `None` if all bits are set.
+    #[inline(always)]
+    pub fn last_zero(&self) -> Option<usize> {
+        self.find_last(false)
+    }
+    /// Returns the number of zeros at the most significant end of the slice.
+    ///
+    /// Like [`BitSlice::get_field`], this treats the slice as an integer whose most significant
+    /// bit is decided by the bit order. With [`Lsb0`](crate::Lsb0) these are the zeros at the end
+    /// of the slice, with [`Msb0`](crate::Msb0) the zeros at its start.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Lsb0};
+    /// let data = [0b0001_0100u8];
+    /// let bits: BitSlice<_, Lsb0, LittleEndian> = data.as_ref().into();
+    /// assert_eq!(bits.leading_zeros(), 3);
+    /// assert_eq!(bits.trailing_zeros(), 2);
+    /// ```
+    pub fn leading_zeros(&self) -> usize {
+        if self.bit_order.msb_first() {
+            self.first_one().unwrap_or(self.len())
+        } else {
+            self.last_one().map_or(self.len(), |i| self.len() - 1 - i)
+        }
+    }
+    /// Returns the number of zeros at the least significant end of the slice.
+    ///
+    /// See [`BitSlice::leading_zeros`].
+    pub fn trailing_zeros(&self) -> usize {
+        if self.bit_order.msb_first() {
+            self.last_one().map_or(self.len(), |i| self.len() - 1 - i)
+        } else {
+            self.first_one().unwrap_or(self.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::for_each_order;
+    use crate::{BitOrder, BitSlice, ByteOrder, LittleEndian, Lsb0, Msb0};
+
+    /// Checks the word-based searches against a bit by bit search over many sub-slices.
+    fn check_search<B: BitOrder + Default, E: ByteOrder + Default>() {
+        let mut data = [0u8; 24];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = match i % 6 {
+                0 => 0,
+                1 => 0xff,
+                _ => (i * 37) as u8,
+            };
+        }
+        let full: BitSlice<&[u8], B, E> = data.as_ref().into();
+        for start in [0, 1, 7, 8, 13, 64, 100] {
+            for end in [start, start + 1, start + 9, start + 64, start + 65, 192] {
+                let slice = full.slice(start..end.min(192));
+                let bits: [bool; 192] =
+                    core::array::from_fn(|i| i < slice.len() && slice.get_bit(i));
+                let len = slice.len();
+                let ones = bits[..len].iter().filter(|&&b| b).count();
+                assert_eq!(slice.count_ones(), ones);
+                assert_eq!(slice.count_zeros(), len - ones);
+                assert_eq!(slice.first_one(), (0..len).find(|&i| bits[i]));
+                assert_eq!(slice.first_zero(), (0..len).find(|&i| !bits[i]));
+                assert_eq!(slice.last_one(), (0..len).rev().find(|&i| bits[i]));
+                assert_eq!(slice.last_zero(), (0..len).rev().find(|&i| !bits[i]));
+                let zeros_from_start = (0..len).take_while(|&i| !bits[i]).count();
+                let zeros_from_end = (0..len).rev().take_while(|&i| !bits[i]).count();
+                if B::default().msb_first() {
+                    assert_eq!(slice.leading_zeros(), zeros_from_start);
+                    assert_eq!(slice.trailing_zeros(), zeros_from_end);
+                } else {
+                    assert_eq!(slice.leading_zeros(), zeros_from_end);
+                    assert_eq!(slice.trailing_zeros(), zeros_from_start);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        for_each_order!(check_search);
+    }
+
+    #[test]
+    fn test_matches_integers() {
+        let value = 0x0012_3400_u32;
+        let data = value.to_le_bytes();
+        let bits: BitSlice<_, Lsb0, LittleEndian> = data.as_ref().into();
+        assert_eq!(bits.count_ones(), value.count_ones() as usize);
+        assert_eq!(bits.leading_zeros(), value.leading_zeros() as usize);
+        assert_eq!(bits.trailing_zeros(), value.trailing_zeros() as usize);
+        let data = value.to_be_bytes();
+        let bits: BitSlice<_, Msb0, LittleEndian> = data.as_ref().into();
+        assert_eq!(bits.leading_zeros(), value.leading_zeros() as usize);
+        assert_eq!(bits.trailing_zeros(), value.trailing_zeros() as usize);
+        let empty: BitSlice<&[u8]> = BitSlice::new(&[], 0);
+        assert_eq!(empty.first_one(), None);
+        assert_eq!(empty.leading_zeros(), 0);
+    }
+}
@@ -0,0 +1,390 @@
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+
+use crate::storage::words_as_bytes_mut;
+use crate::{BitIterMut, BitOrder, BitSlice, BitStore, ByteOrder, Error, Layout, Signed, Unsigned};
+
+/// The maximum number of bits accessed at a time, enough for any integer field.
+const MAX_BITS: usize = 128;
+/// The maximum number of bytes holding [`MAX_BITS`] bits, reached with `u64` words.
+const MAX_BYTES: usize = MAX_BITS / 8 + 2 * 8;
+
+/// The storage of mutable [`BitSlice`]s that may share bytes with each other, such as the two
+/// parts returned by [`BitSlice::split_at_mut`].
+///
+/// The bytes are kept as [`Cell`]s and every write is a read-modify-write of the bytes holding
+/// the written bits, like the writes through a [`BitMut`](crate::BitMut), so bits of other
+/// slices in the same bytes keep their value. Such slices are not [`Send`] and are therefore
+/// only ever used from one thread.
+///
+/// Accesses to fields read the bytes holding the accessed bits into a local copy and work on
+/// that with the methods of [`BitSlice`]. Writes then store the bits of the field back, leaving
+/// all other bits untouched. Accesses cover at most 128 bits. Slices that are split at the
+/// start of a word with [`BitSlice::split_at_mut_aligned`] share no bytes and keep the whole API
+/// of [`BitSlice`] instead.
+///
+/// The bytes are those of a storage of `W` words, see [`BitStorage`](crate::BitStorage).
+pub struct SharedBytes<'a, W = u8>(&'a [Cell<u8>], PhantomData<W>);
+
+impl<'a, W: BitStore, B: BitOrder, Endian: ByteOrder> BitSlice<SharedBytes<'a, W>, B, Endian> {
+    /// Reads the words holding the bits in `range`, calls `f` with a [`BitSlice`] over these
+    /// bits and, if `write` is `true` and `f` succeeds, writes the bits back.
+    fn access<R>(
+        &self,
+        range: impl RangeBounds<usize>,
+        write: bool,
+        f: impl FnOnce(&mut BitSlice<&mut [W], B, Endian>) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        let (start, end) = self.try_bit_range(range)?;
+        if end - start > MAX_BITS {
+            return Err(Error::StorageTooSmall {
+                bits: end - start,
+                capacity: MAX_BITS,
+            });
+        }
+        // The bytes are copied in whole words, which keeps the position of every bit within
+        // them, and like in `split_at_mut` relative to the used end of the storage.
+        let word_bytes = Layout::<Endian, W>::WORD_BYTES;
+        let (pos, pos_end) = (self.range.start + start, self.range.start + end);
+        let (first, last) = (pos / (8 * word_bytes), pos_end.div_ceil(8 * word_bytes));
+        let count = (last - first) * word_bytes;
+        let offset = if self.byte_order.starts_at_end() {
+            self.bytes.0.len() - last * word_bytes
+        } else {
+            first * word_bytes
+        };
+        let cells = &self.bytes.0[offset..offset + count];
+        let range = pos - 8 * first * word_bytes..pos_end - 8 * first * word_bytes;
+
+        let words = count / (W::BITS / 8);
+        let mut copy = [W::default(); MAX_BYTES];
+        for (byte, cell) in words_as_bytes_mut(&mut copy[..words]).iter_mut().zip(cells) {
+            *byte = cell.get();
+        }
+        let mut bits = BitSlice {
+            bytes: &mut copy[..words],
+            range: range.clone(),
+            bit_order: self.bit_order,
+            byte_order: self.byte_order,
+        };
+        let result = f(&mut bits)?;
+        if write {
+            // Only the bits in `range` are stored, so that writes to other bits of the bytes,
+            // even from within `f`, are kept.
+            let mut mask = [W::default(); MAX_BYTES];
+            let mut bits = BitSlice {
+                bytes: &mut mask[..words],
+                range,
+                bit_order: self.bit_order,
+                byte_order: self.byte_order,
+            };
+            bits.fill_bits(0..end - start, true);
+            let bytes = words_as_bytes_mut(&mut copy[..words]).iter();
+            let mask = words_as_bytes_mut(&mut mask[..words]).iter();
+            for ((cell, byte), mask) in cells.iter().zip(bytes).zip(mask) {
+                cell.set(cell.get() & !mask | byte & mask);
+            }
+        }
+        Ok(result)
+    }
+    /// Returns the cell of the byte holding bit `n` and the mask of the bit within it.
+    fn cell(&self, n: usize) -> (&Cell<u8>, u8) {
+        let (byte, bit) = self.bit_order.find_bit(
+            Layout::<Endian, W>::new(self.byte_order),
+            self.range.start + n,
+            self.bytes.0.len() * 8,
+        );
+        (&self.bytes.0[byte], 1 << bit)
+    }
+    /// Retrieves the value of a bit at a specified index, see [`BitSlice::get_bit`].
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds. See [`BitSlice::get`] for a non-panicking version.
+    #[track_caller]
+    pub fn get_bit(&self, n: usize) -> bool {
+        assert!(
+            n < self.len(),
+            "{}",
+            Error::IndexOutOfBounds {
+                index: n,
+                len: self.len()
+            }
+        );
+        let (cell, mask) = self.cell(n);
+        cell.get() & mask != 0
+    }
+    /// Retrieves the value of a bit at a specified index, or `None` if `n` is out of bounds.
+    #[inline(always)]
+    pub fn get(&self, n: usize) -> Option<bool> {
+        (n < self.len()).then(|| self.get_bit(n))
+    }
+    /// Sets the value of a bit at a specified index, see [`BitSlice::set_bit`].
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds. See [`BitSlice::try_set_bit`] for a non-panicking
+    /// version.
+    #[track_caller]
+    pub fn set_bit(&mut self, n: usize, value: bool) {
+        self.try_set_bit(n, value).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Sets the value of a bit at a specified index, or returns [`Error::IndexOutOfBounds`] if
+    /// `n` is out of bounds.
+    pub fn try_set_bit(&mut self, n: usize, value: bool) -> Result<(), Error> {
+        if n >= self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index: n,
+                len: self.len(),
+            });
+        }
+        let (cell, mask) = self.cell(n);
+        cell.set(if value {
+            cell.get() | mask
+        } else {
+            cell.get() & !mask
+        });
+        Ok(())
+    }
+    /// Reads the bits in `range` and calls `f` with a [`BitSlice`] over them.
+    ///
+    /// # Returns
+    /// The result of `f`, [`Error::RangeOutOfBounds`] if `range` is out of bounds or
+    /// [`Error::StorageTooSmall`] if it has more than 128 bits.
+    pub fn read<R>(
+        &self,
+        range: impl RangeBounds<usize>,
+        f: impl FnOnce(&BitSlice<&[W], B, Endian>) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        self.access(range, false, |bits| f(&bits.slice(..)))
+    }
+    /// Reads the bits in `range`, calls `f` with a mutable [`BitSlice`] over them and writes
+    /// them back if `f` succeeds.
+    ///
+    /// Bits outside of `range` keep their value, even if they share a byte with it.
+    ///
+    /// # Returns
+    /// The result of `f`, [`Error::RangeOutOfBounds`] if `range` is out of bounds or
+    /// [`Error::StorageTooSmall`] if it has more than 128 bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitSlice, LittleEndian, Lsb0};
+    /// let mut data = [0u8; 2];
+    /// let mut bits: BitSlice<_, Lsb0, LittleEndian> = data.as_mut().into();
+    /// let (mut head, mut tail) = bits.split_at_mut(5);
+    /// head.modify(.., |bits| {
+    ///     bits.invert();
+    ///     Ok(())
+    /// })
+    /// .unwrap();
+    /// tail.modify(.., |bits| bits.set_field(..4, 0x9u8)).unwrap();
+    /// assert_eq!(data, [0x3f, 0x01]);
+    /// ```
+    pub fn modify<R>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        f: impl FnOnce(&mut BitSlice<&mut [W], B, Endian>) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        self.access(range, true, f)
+    }
+    /// Reads an unsigned integer from a range of bits, see [`BitSlice::get_field`].
+    pub fn get_field<T: Unsigned>(&self, range: impl RangeBounds<usize>) -> Result<T, Error> {
+        self.read(range, |bits| bits.get_field(..))
+    }
+    /// Reads a signed integer from a range of bits, see [`BitSlice::get_signed_field`].
+    pub fn get_signed_field<T: Signed>(&self, range: impl RangeBounds<usize>) -> Result<T, Error> {
+        self.read(range, |bits| bits.get_signed_field(..))
+    }
+    /// Writes an unsigned integer into a range of bits, see [`BitSlice::set_field`].
+    pub fn set_field<T: Unsigned>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        self.modify(range, |bits| bits.set_field(.., value))
+    }
+    /// Writes a signed integer into a range of bits, see [`BitSlice::set_signed_field`].
+    pub fn set_signed_field<T: Signed>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        self.modify(range, |bits| bits.set_signed_field(.., value))
+    }
+    /// Returns an [`Iterator`] over mutable references to all bits in the slice, see
+    /// [`BitSlice::iter_mut`].
+    pub fn iter_mut(&mut self) -> BitIterMut<'_, B, Endian, W> {
+        BitIterMut {
+            bytes: self.bytes.0,
+            range: self.range.clone(),
+            bit_order: self.bit_order,
+            byte_order: Layout::new(self.byte_order),
+        }
+    }
+    /// Returns a mutable sub-slice, see [`BitSlice::slice_mut`].
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds. See [`BitSlice::try_slice_mut`] for a non-panicking
+    /// version.
+    #[track_caller]
+    pub fn slice_mut(
+        &mut self,
+        range: impl RangeBounds<usize>,
+    ) -> BitSlice<SharedBytes<'_, W>, B, Endian> {
+        self.try_slice_mut(range).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Returns a mutable sub-slice, or [`Error::RangeOutOfBounds`] if `range` is out of
+    /// bounds.
+    pub fn try_slice_mut(
+        &mut self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<BitSlice<SharedBytes<'_, W>, B, Endian>, Error> {
+        let (start, end) = self.try_bit_range(range)?;
+        Ok(BitSlice {
+            bytes: SharedBytes(self.bytes.0, PhantomData),
+            range: self.range.start + start..self.range.start + end,
+            bit_order: self.bit_order,
+            byte_order: self.byte_order,
+        })
+    }
+    /// Divides the slice into two mutable slices at an index, see [`BitSlice::split_at_mut`].
+    ///
+    /// # Panics
+    /// Panics if `mid > len()`. See [`BitSlice::try_split_at_mut`] for a non-panicking version.
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (
+        BitSlice<SharedBytes<'_, W>, B, Endian>,
+        BitSlice<SharedBytes<'_, W>, B, Endian>,
+    ) {
+        self.try_split_at_mut(mid).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Divides the slice into two mutable slices at an index, or returns
+    /// [`Error::IndexOutOfBounds`] if `mid > len()`.
+    #[allow(clippy::type_complexity)]
+    pub fn try_split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> Result<
+        (
+            BitSlice<SharedBytes<'_, W>, B, Endian>,
+            BitSlice<SharedBytes<'_, W>, B, Endian>,
+        ),
+        Error,
+    > {
+        split(
+            self.bytes.0,
+            self.range.start,
+            self.range.end,
+            mid,
+            self.bit_order,
+            self.byte_order,
+        )
+    }
+}
+
+/// Splits the bits `start..end` of `bytes` at index `mid` into two slices sharing the bytes.
+#[allow(clippy::type_complexity)]
+pub(crate) fn split<W, B: Copy, Endian: Copy>(
+    bytes: &[Cell<u8>],
+    start: usize,
+    end: usize,
+    mid: usize,
+    bit_order: B,
+    byte_order: Endian,
+) -> Result<
+    (
+        BitSlice<SharedBytes<'_, W>, B, Endian>,
+        BitSlice<SharedBytes<'_, W>, B, Endian>,
+    ),
+    Error,
+> {
+    if mid > end - start {
+        return Err(Error::IndexOutOfBounds {
+            index: mid,
+            len: end - start,
+        });
+    }
+    Ok((
+        BitSlice {
+            bytes: SharedBytes(bytes, PhantomData),
+            range: start..start + mid,
+            bit_order,
+            byte_order,
+        },
+        BitSlice {
+            bytes: SharedBytes(bytes, PhantomData),
+            range: start + mid..end,
+            bit_order,
+            byte_order,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::for_each_order;
+    use crate::{BitOrder, BitSlice, ByteOrder, Error, Msb0, Words};
+
+    /// Writes fields through both parts of a split, which share the byte at the split point.
+    fn check_shared_byte<B: BitOrder + Default, E: ByteOrder + Default>() {
+        for mid in [3, 9, 12, 16, 21] {
+            let mut data = [0u8; 4];
+            let mut bits: BitSlice<&mut [u8], B, E> = data.as_mut().into();
+            let mut part = bits.slice_mut(2..30);
+            let (mut head, mut tail) = part.split_at_mut(mid);
+            assert_eq!((head.len(), tail.len()), (mid, 28 - mid));
+            head.set_field(.., (1u32 << mid) - 1).unwrap();
+            tail.set_field(..3, 0b101u8).unwrap();
+            tail.set_bit(tail.len() - 1, true);
+            // Writing the head again does not undo the writes to the tail and vice versa.
+            head.set_bit(mid - 1, false);
+            tail.modify(3..5, |bits| bits.set_field(.., 0b11u8))
+                .unwrap();
+            assert_eq!(tail.get_field::<u8>(..3), Ok(0b101));
+            assert!(!head.get_bit(mid - 1) && tail.get_bit(0));
+
+            let result: BitSlice<&[u8], B, E> = data.as_ref().into();
+            for i in 0..32 {
+                let expected = match i {
+                    2..30 if i - 2 < mid => i - 2 != mid - 1,
+                    2..30 => matches!(i - 2 - mid, 0 | 2 | 3 | 4) || i == 29,
+                    _ => false,
+                };
+                assert_eq!(result.get_bit(i), expected, "mid {mid}, bit {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_shared_byte() {
+        for_each_order!(check_shared_byte);
+        check_shared_byte::<Msb0, Words<u16>>();
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut data = [0u8; 20];
+        let mut bits: BitSlice<_> = data.as_mut().into();
+        let (mut head, tail) = bits.split_at_mut(150);
+        assert_eq!(
+            head.set_field(..129, 0u8),
+            Err(Error::StorageTooSmall {
+                bits: 129,
+                capacity: 128
+            })
+        );
+        assert_eq!(
+            head.try_set_bit(150, true),
+            Err(Error::IndexOutOfBounds {
+                index: 150,
+                len: 150
+            })
+        );
+        assert_eq!(tail.get(10), None);
+        assert!(head.try_split_at_mut(151).is_err());
+    }
+}
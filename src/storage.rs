@@ -0,0 +1,142 @@
+use crate::BitStore;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The `BitStorage` trait is implemented by all storage types a [`BitSlice`](crate::BitSlice)
+/// can read from.
+///
+/// The storage is a sequence of [`BitStore`] words, such as `[u8; N]`, `&[u16]`, `&mut [u32]` or,
+/// with the `alloc` feature, `Vec<u64>`. The bits are numbered word by word, and within a word
+/// [`Lsb0`](crate::Lsb0) numbers them from its least significant bit and [`Msb0`](crate::Msb0)
+/// from its most significant one, like with the [`Words`](crate::Words) byte order. The byte
+/// order decides whether the first bits are in the first word, as with
+/// [`LittleEndian`](crate::LittleEndian), or in the last one, as with
+/// [`BigEndian`](crate::BigEndian).
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BitSlice, LittleEndian, Msb0};
+/// let mut regs = [0u32; 2];
+/// let mut bits: BitSlice<_, Msb0, LittleEndian> = BitSlice::new(&mut regs, 64);
+/// bits.set_bit(0, true);
+/// bits.set_field(36..40, 0xau8).unwrap();
+/// assert_eq!(regs, [0x8000_0000, 0x0a00_0000]);
+/// ```
+pub trait BitStorage: private::Sealed {
+    /// The type of the words of the storage.
+    type Word: BitStore;
+    /// Returns the words of the storage.
+    fn as_words(&self) -> &[Self::Word];
+}
+
+/// The `BitStorageMut` trait is implemented by all storage types a
+/// [`BitSlice`](crate::BitSlice) can write into, see [`BitStorage`].
+pub trait BitStorageMut: BitStorage {
+    /// Returns the words of the storage mutably.
+    fn as_words_mut(&mut self) -> &mut [Self::Word];
+}
+
+impl<W: BitStore> private::Sealed for [W] {}
+impl<W: BitStore> BitStorage for [W] {
+    type Word = W;
+    #[inline(always)]
+    fn as_words(&self) -> &[W] {
+        self
+    }
+}
+impl<W: BitStore> BitStorageMut for [W] {
+    #[inline(always)]
+    fn as_words_mut(&mut self) -> &mut [W] {
+        self
+    }
+}
+impl<W: BitStore, const N: usize> private::Sealed for [W; N] {}
+impl<W: BitStore, const N: usize> BitStorage for [W; N] {
+    type Word = W;
+    #[inline(always)]
+    fn as_words(&self) -> &[W] {
+        self
+    }
+}
+impl<W: BitStore, const N: usize> BitStorageMut for [W; N] {
+    #[inline(always)]
+    fn as_words_mut(&mut self) -> &mut [W] {
+        self
+    }
+}
+impl<T: BitStorage + ?Sized> private::Sealed for &T {}
+impl<T: BitStorage + ?Sized> BitStorage for &T {
+    type Word = T::Word;
+    #[inline(always)]
+    fn as_words(&self) -> &[T::Word] {
+        (**self).as_words()
+    }
+}
+impl<T: BitStorage + ?Sized> private::Sealed for &mut T {}
+impl<T: BitStorage + ?Sized> BitStorage for &mut T {
+    type Word = T::Word;
+    #[inline(always)]
+    fn as_words(&self) -> &[T::Word] {
+        (**self).as_words()
+    }
+}
+impl<T: BitStorageMut + ?Sized> BitStorageMut for &mut T {
+    #[inline(always)]
+    fn as_words_mut(&mut self) -> &mut [T::Word] {
+        (**self).as_words_mut()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<W: BitStore> private::Sealed for alloc::vec::Vec<W> {}
+#[cfg(feature = "alloc")]
+impl<W: BitStore> BitStorage for alloc::vec::Vec<W> {
+    type Word = W;
+    #[inline(always)]
+    fn as_words(&self) -> &[W] {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<W: BitStore> BitStorageMut for alloc::vec::Vec<W> {
+    #[inline(always)]
+    fn as_words_mut(&mut self) -> &mut [W] {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T: BitStorage + ?Sized> private::Sealed for alloc::boxed::Box<T> {}
+#[cfg(feature = "alloc")]
+impl<T: BitStorage + ?Sized> BitStorage for alloc::boxed::Box<T> {
+    type Word = T::Word;
+    #[inline(always)]
+    fn as_words(&self) -> &[T::Word] {
+        (**self).as_words()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T: BitStorageMut + ?Sized> BitStorageMut for alloc::boxed::Box<T> {
+    #[inline(always)]
+    fn as_words_mut(&mut self) -> &mut [T::Word] {
+        (**self).as_words_mut()
+    }
+}
+
+/// Returns the memory of `words` as bytes.
+#[inline(always)]
+pub(crate) fn words_as_bytes<W: BitStore>(words: &[W]) -> &[u8] {
+    let len = core::mem::size_of_val(words);
+    // SAFETY: `W` is an unsigned integer, whose memory is `len` initialized bytes without
+    // padding, and bytes have no alignment requirement.
+    unsafe { core::slice::from_raw_parts(words.as_ptr().cast::<u8>(), len) }
+}
+/// Returns the memory of `words` as mutable bytes.
+#[inline(always)]
+pub(crate) fn words_as_bytes_mut<W: BitStore>(words: &mut [W]) -> &mut [u8] {
+    let len = core::mem::size_of_val(words);
+    // SAFETY: `W` is an unsigned integer, whose memory is `len` initialized bytes without
+    // padding, bytes have no alignment requirement, and every byte pattern is a valid `W`, so
+    // the words stay valid whatever bytes are written.
+    unsafe { core::slice::from_raw_parts_mut(words.as_mut_ptr().cast::<u8>(), len) }
+}
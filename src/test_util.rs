@@ -0,0 +1,26 @@
+//! Helpers shared by the tests of the crate root and of the modules.
+
+/// Returns a xorshift generator of pseudo-random numbers, so that randomized tests are
+/// reproducible.
+pub(crate) fn random(seed: u64) -> impl FnMut() -> u64 {
+    let mut state = seed;
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }
+}
+
+/// Calls a generic `check` function with all four combinations of [`Lsb0`](crate::Lsb0) or
+/// [`Msb0`](crate::Msb0) and [`LittleEndian`](crate::LittleEndian) or
+/// [`BigEndian`](crate::BigEndian).
+macro_rules! for_each_order {
+    ($check:ident) => {
+        $check::<$crate::Lsb0, $crate::LittleEndian>();
+        $check::<$crate::Lsb0, $crate::BigEndian>();
+        $check::<$crate::Msb0, $crate::LittleEndian>();
+        $check::<$crate::Msb0, $crate::BigEndian>();
+    };
+}
+pub(crate) use for_each_order;
@@ -1,7 +1,9 @@
 use super::*;
+use crate::test_util::{for_each_order, random};
 extern crate alloc;
 extern crate std;
 use alloc::vec;
+use alloc::vec::Vec;
 use std::println;
 
 #[test]
@@ -16,7 +18,113 @@ fn test_macro() {
     assert_eq!(bits.next(), Some(true));
     assert_eq!(bits.next(), None);
 }
+/// Creates bits with the list form of `bits!`, which works with every bit and byte order.
+fn check_macro_list<B: BitOrder + Default, E: ByteOrder + Default>() {
+    let bits: BitSlice<_, B, E> = bits![1, 0, 0, 1, 1, true, 0, 1, 1];
+    assert!(bits == [true, false, false, true, true, true, false, true, true]);
+    let bits: BitSlice<_, B, E> = bits![1];
+    assert!(bits == [true]);
+    let bits: BitSlice<_, B, E> = bits![];
+    assert!(bits.is_empty());
+}
+
+#[test]
+fn test_macro_orders() {
+    for_each_order!(check_macro_list);
+    check_macro_list::<DynBitOrder, DynEndian>();
+    check_macro_list::<Msb0, DynEndian>();
+}
+
+static SYNC_WORD: BitSlice<[u8; 3], Msb0, BigEndian> = bits!("1011_0010 0111 0100_1");
+const PATTERN: BitSlice<[u8; 2], Lsb0, BigEndian> = bits!(0x2c5; 11);
+
 #[test]
+fn test_const_macro() {
+    assert_eq!(SYNC_WORD.len(), 17);
+    assert!(
+        SYNC_WORD
+            == "10110010011101001"
+                .bytes()
+                .map(|c| c == b'1')
+                .collect::<Vec<_>>()
+    );
+    assert!(SYNC_WORD == BitSlice::<_, Msb0, BigEndian>::new([0x80u8, 0x74, 0xb2], 17).slice(..));
+    assert_eq!(PATTERN.get_field::<u16>(..), Ok(0x2c5));
+    let bits: BitSlice<_, Msb0, LittleEndian> = bits!(0x2c5; 11);
+    assert!(bits == "01011000101".bytes().map(|c| c == b'1').collect::<Vec<_>>());
+
+    // Single literals are bits, like in the list form.
+    let bits: BitSlice<_, Lsb0, LittleEndian> = bits![1];
+    assert!(bits == [true]);
+    let bits: BitSlice<_, Lsb0, LittleEndian> = bits![0x0_0];
+    assert!(bits == [false]);
+    let bits: BitSlice<_, Lsb0, LittleEndian> = bits![0x10u8];
+    assert!(bits == [true]);
+    let bits: BitSlice<_, Lsb0, LittleEndian> = bits![false];
+    assert!(bits == [false]);
+    let bits: BitSlice<_, DynBitOrder, DynEndian> = bits![0x10u8];
+    assert!(bits == [true]);
+    let bits: BitSlice<_, DynBitOrder, DynEndian> = bits![2];
+    assert!(bits == [true]);
+    let bits: BitSlice<_, DynBitOrder, DynEndian> = bits![0];
+    assert!(bits == [false]);
+    let bits: BitSlice<_, Lsb0, LittleEndian> = bits!("");
+    assert!(bits.is_empty());
+
+    let bits: BitSlice<[u8; 17], Msb0, BigEndian> = BitSlice::from_uint(u128::MAX, 130);
+    assert_eq!(bits.count_ones(), 128);
+    assert_eq!(bits.slice(..2).count_ones(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_from_uint_overflow() {
+    let _: BitSlice<[u8; 1], Lsb0, LittleEndian> = BitSlice::from_uint(0x10, 4);
+}
+
+bitfield! {
+    struct Header([u8; 3]) {
+        version, set_version: u8 = 0..3;
+        flag, set_flag: bool = 3..4;
+        length, set_length: u16 = 4..17;
+        delta, set_delta: i8 = 17..24;
+    }
+}
+
+bitfield! {
+    struct Register([u8; 4], Msb0, BigEndian) {
+        channel, set_channel: u8 = 2..6;
+        counter, set_counter: u32 = 6..30;
+    }
+}
+
+#[test]
+fn test_bitfield() {
+    let mut header = Header::from_bytes([0; 3]);
+    header.set_version(5).unwrap();
+    header.set_flag(true).unwrap();
+    header.set_length(0x1abc).unwrap();
+    header.set_delta(-37).unwrap();
+    assert_eq!(header.set_version(8), Err(Error::Overflow));
+    assert_eq!(header.version(), Ok(5));
+    assert_eq!(header.flag(), Ok(true));
+    assert_eq!(header.length(), Ok(0x1abc));
+    assert_eq!(header.delta(), Ok(-37));
+    let bits = header.bits();
+    assert_eq!(bits.get_field::<u8>(0..3), Ok(5));
+    assert_eq!(bits.get_signed_field::<i8>(17..24), Ok(-37));
+    assert_eq!(header.into_bytes(), [0xcd, 0xab, 0xb7]);
+
+    let mut register = Register::from_bytes([0xff; 4]);
+    register.set_channel(0xa).unwrap();
+    register.set_counter(0).unwrap();
+    assert_eq!(register.channel(), Ok(0xa));
+    assert_eq!(register.counter(), Ok(0));
+    assert_eq!(register.into_bytes(), [0x03, 0x00, 0x00, 0xe8]);
+}
+
+#[test]
+#[allow(clippy::drop_non_drop)]
 fn test_slice() {
     let mut x = [1u8, 2, 3, 4];
     let bits: BitSlice<_, Lsb0, LittleEndian> = x.as_mut().into();
@@ -42,6 +150,7 @@ fn test_slice() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_iter() {
     let mut x = [1u8, 2, 3, 4];
     let bits: BitSlice<_, Lsb0, LittleEndian> = x.as_mut().into();
@@ -73,6 +182,7 @@ fn test_iter() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_lsb0_litle_endian() {
     let mut x = [1u8, 2, 3, 4];
     let mut bits: BitSlice<_, Lsb0, LittleEndian> = x.as_mut().into();
@@ -90,6 +200,7 @@ fn test_lsb0_litle_endian() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_msb0_litle_endian() {
     let mut x = [1u8, 2, 3, 4];
     let mut bits: BitSlice<_, Msb0, LittleEndian> = x.as_mut().into();
@@ -103,6 +214,7 @@ fn test_msb0_litle_endian() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_lsb0_big_endian() {
     let mut x = [1u8, 2, 3, 4];
     let mut bits: BitSlice<_, Lsb0, BigEndian> = x.as_mut().into();
@@ -118,6 +230,7 @@ fn test_lsb0_big_endian() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_msb0_big_endian() {
     let mut x = [1u8, 2, 3, 4];
     let mut bits: BitSlice<_, Msb0, BigEndian> = x.as_mut().into();
@@ -161,7 +274,84 @@ fn into_int() {
     println!("{:b} {:b}", v, x.unwrap());
     assert_eq!(x.unwrap(), v);
 }
-fn push<S: AsMut<[u8]>, B: BitOrder, E: ByteOrder>(
+#[test]
+fn into_u128() {
+    let v = 0x8123_4567_89ab_cdef_0011_2233_4455_6677u128;
+    let bits: BitSlice<_, Msb0, BigEndian> = v.into();
+    assert_eq!(u128::try_from(bits.clone()), Ok(v));
+    assert_eq!(u64::try_from(bits), Err(Error::ConversionError));
+
+    // Wider slices are accepted as long as the excess bits are zero.
+    let x = [0xffu8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    let bits: BitSlice<_> = x.as_ref().into();
+    assert_eq!(u8::try_from(bits.clone()), Ok(0xff));
+    assert_eq!(u128::try_from(bits.clone()), Ok(0xff));
+    assert_eq!(bits.slice(4..).to_u64(), 0xf);
+    let bits: BitSlice<_, Msb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(u128::try_from(bits.clone()), Ok(0xff));
+    assert_eq!(bits.get_field::<u128>(..), Err(Error::ConversionError));
+}
+
+#[test]
+fn test_int_conversions_are_lsb_first() {
+    // The first bit of the slice is the least significant bit of the value in every bit order.
+    assert!(BitSlice::<_, Msb0, LittleEndian>::from(1u8).get_bit(0));
+    assert!(BitSlice::<_, Msb0, BigEndian>::from(1u32).get_bit(0));
+    let bits: BitSlice<_, Msb0, LittleEndian> = bits![1, 0, 0];
+    assert_eq!(bits.to_u64(), 1);
+    assert_eq!(bits.try_to_u64(), Ok(1));
+    let x = [0x80u8];
+    let bits: BitSlice<_, Msb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(u8::try_from(bits.clone()), Ok(1));
+    assert_eq!(i8::try_from(bits), Ok(1));
+    let x = [0x01u8];
+    let bits: BitSlice<_, Msb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(i8::try_from(bits), Ok(-128));
+    let bits: BitSlice<_, Msb0, BigEndian> = (-2i16).into();
+    assert!(!bits.get_bit(0) && bits.get_bit(1) && bits.get_bit(15));
+    assert_eq!(i16::try_from(bits), Ok(-2));
+}
+
+/// Round-trips values of every width from 1 to 128 bits through `From` and `TryFrom`.
+fn check_int_round_trip<B: BitOrder + Default, E: ByteOrder + Default>() {
+    let mut random = random(0x9e37_79b9_7f4a_7c15);
+    for width in 1..=128 {
+        for _ in 0..8 {
+            let value = ((random() as u128) << 64 | random() as u128) >> (128 - width);
+            let bits: BitSlice<_, B, E> = value.into();
+            assert_eq!(u128::try_from(bits.clone()), Ok(value));
+            assert_eq!(u64::try_from(bits.clone()).ok(), value.try_into().ok());
+            assert_eq!(u8::try_from(bits.clone()).ok(), value.try_into().ok());
+            assert_eq!(bits.try_to_u64().ok(), value.try_into().ok());
+            let signed = BitSlice::<_, B, E>::from(value as i128);
+            assert_eq!(i128::try_from(signed), Ok(value as i128));
+
+            // The same value in a slice of exactly `width` bits at an odd offset.
+            let mut x = [0u8; 18];
+            let mut storage: BitSlice<_, B, E> = x.as_mut().into();
+            let offset = (random() % 16) as usize;
+            storage.set_field(offset..offset + width, value).unwrap();
+            let field = storage.slice(offset..offset + width);
+            assert_eq!(field.get_field::<u128>(..), Ok(value));
+            if width < 128 {
+                let field = storage.slice(offset..offset + width + 1);
+                assert_eq!(
+                    field.get_field::<u128>(..),
+                    Ok(value << (B::default().msb_first() as u32))
+                );
+            }
+            let signed = ((value << (128 - width)) as i128) >> (128 - width);
+            assert_eq!(field.get_signed_field::<i128>(..), Ok(signed));
+        }
+    }
+}
+
+#[test]
+fn test_int_round_trip() {
+    for_each_order!(check_int_round_trip);
+}
+
+fn push<S: BitStorageMut, B: BitOrder, E: ByteOrder>(
     bits: &mut BitSlice<S, B, E>,
 ) -> Result<(), crate::Error> {
     bits.push(true)?;
@@ -179,7 +369,7 @@ fn push<S: AsMut<[u8]>, B: BitOrder, E: ByteOrder>(
 }
 #[test]
 fn test_push() -> Result<(), crate::Error> {
-    let mut x = [0, 0, 0, 0];
+    let mut x = [0u8, 0, 0, 0];
     let mut bits: BitSlice<_, Msb0, BigEndian> = BitSlice::new(&mut x, 0);
     push(&mut bits)?;
     assert_eq!(&bits, &bits![1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 1]);
@@ -202,3 +392,727 @@ fn test_push() -> Result<(), crate::Error> {
 
     Ok(())
 }
+
+/// Checks the word-at-a-time paths against `get_bit` for one order combination.
+fn check_word_paths<B: BitOrder + Default, E: ByteOrder + Default>() {
+    let data: alloc::vec::Vec<u8> = (0..24u32).map(|i| (i * 73 + 5) as u8).collect();
+    let bits: BitSlice<&[u8], B, E> = data.as_slice().into();
+    for start in 0..20 {
+        for end in [
+            start,
+            start + 1,
+            start + 63,
+            start + 64,
+            start + 65,
+            bits.len(),
+        ] {
+            let sub = bits.slice(start..end);
+            let expected: alloc::vec::Vec<bool> = (0..sub.len()).map(|i| sub.get_bit(i)).collect();
+            assert_eq!(sub.iter().collect::<alloc::vec::Vec<_>>(), expected);
+            assert!(sub == expected);
+
+            let mut copy = [0u8; 26];
+            let mut target: BitSlice<&mut [u8], B, E> = copy.as_mut().into();
+            let offset = (start * 7) % 11;
+            let mut i = 0;
+            while i < sub.len() {
+                let n = (sub.len() - i).min(64);
+                target.write_bits(offset + i, n, sub.read_bits(i, n));
+                i += n;
+            }
+            assert!(target.slice(offset..offset + sub.len()) == sub);
+            assert!(target.iter().take(offset).all(|b| !b));
+            assert!(target.iter().skip(offset + sub.len()).all(|b| !b));
+        }
+    }
+}
+
+#[test]
+fn test_word_paths() {
+    for_each_order!(check_word_paths);
+}
+
+#[test]
+fn test_unaligned_eq() {
+    let x = [0b1011_0110u8, 0b0000_0001];
+    let y = [0b0110_1100u8, 0b0000_0011];
+    let a: BitSlice<_> = x.as_ref().into();
+    let b: BitSlice<_> = y.as_ref().into();
+    assert_eq!(a.slice(1..9), b.slice(2..10));
+    assert_ne!(a.slice(0..9), b.slice(3..12));
+    assert_ne!(a.slice(1..9), b.slice(2..9));
+}
+
+#[test]
+fn test_iter_nth() {
+    let x: BitSlice<_, Lsb0, BigEndian> = 0x8000_0000_0000_0001_0000_0000_0000_0000u128.into();
+    let mut bits = x.into_iter();
+    assert_eq!(bits.next(), Some(false));
+    assert_eq!(bits.nth(62), Some(false));
+    assert_eq!(bits.next(), Some(true));
+    assert_eq!(bits.len(), 63);
+    assert_eq!(bits.nth(62), Some(true));
+    assert_eq!(bits.next(), None);
+}
+
+#[test]
+fn test_get_field() {
+    let x = [0xa5u8, 0x3c];
+    let bits: BitSlice<_, Lsb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(bits.get_field::<u16>(..), Ok(0x3ca5));
+    assert_eq!(bits.get_field::<u8>(4..12), Ok(0xca));
+    assert_eq!(bits.get_field::<u8>(0..16), Err(Error::ConversionError));
+    assert_eq!(bits.get_field::<u8>(8..16), Ok(0x3c));
+    let bits: BitSlice<_, Lsb0, BigEndian> = x.as_ref().into();
+    assert_eq!(bits.get_field::<u16>(..), Ok(0xa53c));
+    let bits: BitSlice<_, Msb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(bits.get_field::<u16>(..), Ok(0xa53c));
+    assert_eq!(bits.get_field::<u8>(4..12), Ok(0x53));
+    let bits: BitSlice<_, Msb0, BigEndian> = x.as_ref().into();
+    assert_eq!(bits.get_field::<u16>(..), Ok(0x3ca5));
+
+    // Leading zeros beyond the width of the target type are accepted.
+    let x = [0u8, 0, 5];
+    let bits: BitSlice<_, Msb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(bits.get_field::<u8>(..), Ok(5));
+    let bits: BitSlice<_, Lsb0, BigEndian> = x.as_ref().into();
+    assert_eq!(bits.get_field::<u8>(..), Ok(5));
+    assert_eq!(bits.get_field::<u128>(3..3), Ok(0));
+}
+
+#[test]
+fn test_set_field() {
+    let mut x = [0xffu8; 3];
+    let mut bits: BitSlice<_, Lsb0, LittleEndian> = x.as_mut().into();
+    bits.set_field(4..20, 0x1234u16).unwrap();
+    assert_eq!(bits.set_field(0..4, 0x10u8), Err(Error::Overflow));
+    assert_eq!(x, [0x4f, 0x23, 0xf1]);
+
+    let mut x = [0xffu8; 3];
+    let mut bits: BitSlice<_, Msb0, LittleEndian> = x.as_mut().into();
+    bits.set_field(4..20, 0x1234u16).unwrap();
+    assert_eq!(x, [0xf1, 0x23, 0x4f]);
+
+    let mut x = [0xffu8; 3];
+    let mut bits: BitSlice<_, Msb0, LittleEndian> = x.as_mut().into();
+    bits.set_field(.., 0xabu8).unwrap();
+    assert_eq!(x, [0, 0, 0xab]);
+}
+
+/// Writes random values to random fields and reads them back.
+fn check_field_round_trip<B: BitOrder + Default, E: ByteOrder + Default>() {
+    let mut random = random(0x2545_f491_4f6c_dd1d);
+    let mut x = [0u8; 24];
+    for _ in 0..500 {
+        let width = (random() % 128) as usize + 1;
+        let start = (random() % (192 - width as u64 + 1)) as usize;
+        let value = ((random() as u128) << 64 | random() as u128) >> (128 - width);
+        let mut bits: BitSlice<_, B, E> = x.as_mut().into();
+        let before: alloc::vec::Vec<bool> = bits.iter().collect();
+        bits.set_field(start..start + width, value).unwrap();
+        assert_eq!(bits.get_field::<u128>(start..start + width), Ok(value));
+        for (i, bit) in before.iter().enumerate() {
+            if !(start..start + width).contains(&i) {
+                assert_eq!(bits.get_bit(i), *bit);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_field_round_trip() {
+    for_each_order!(check_field_round_trip);
+}
+
+#[test]
+fn test_signed_field() {
+    let x = [0xffu8, 0xe5];
+    let bits: BitSlice<_, Msb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(bits.get_signed_field::<i16>(0..12), Ok(-2));
+    assert_eq!(bits.get_signed_field::<i8>(12..16), Ok(5));
+    assert_eq!(bits.get_signed_field::<i8>(..), Ok(-27));
+    assert_eq!(bits.get_signed_field::<i8>(8..), Ok(-27));
+    assert_eq!(bits.get_signed_field::<i8>(4..16), Ok(-27));
+    assert_eq!(bits.get_signed_field::<i8>(3..16), Ok(-27));
+    assert_eq!(bits.get_signed_field::<i8>(9..16), Ok(-27));
+    assert_eq!(bits.get_signed_field::<i8>(10..16), Ok(0b100101 - 64));
+    assert_eq!(bits.get_signed_field::<i8>(0..0), Ok(0));
+
+    let x = [0x00u8, 0x80];
+    let bits: BitSlice<_, Lsb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(bits.get_signed_field::<i16>(..), Ok(i16::MIN));
+    assert_eq!(bits.get_signed_field::<i8>(..), Err(Error::ConversionError));
+    assert_eq!(bits.get_signed_field::<i8>(8..), Ok(i8::MIN));
+
+    let mut x = [0u8; 20];
+    let mut bits: BitSlice<_, Lsb0, BigEndian> = x.as_mut().into();
+    for value in [0i128, 1, -1, i128::MAX, i128::MIN, -12345] {
+        bits.set_signed_field(4..150, value).unwrap();
+        assert_eq!(bits.get_signed_field::<i128>(4..150), Ok(value));
+    }
+    bits.set_signed_field(0..12, -2048i16).unwrap();
+    assert_eq!(bits.set_signed_field(0..12, 2048i16), Err(Error::Overflow));
+    assert_eq!(bits.set_signed_field(0..12, -2049i16), Err(Error::Overflow));
+    assert_eq!(bits.get_signed_field::<i16>(0..12), Ok(-2048));
+    bits.set_field(0..130, 1u128 << 127).unwrap();
+    assert_eq!(
+        bits.get_signed_field::<i128>(0..130),
+        Err(Error::ConversionError)
+    );
+}
+
+#[test]
+fn test_sign_magnitude_and_ones_complement() {
+    let x = [0b1000_0101u8, 0b1111_1010, 0b1000_0000];
+    let bits: BitSlice<_, Msb0, LittleEndian> = x.as_ref().into();
+    assert_eq!(bits.get_sign_magnitude_field::<i8>(0..8), Ok(-5));
+    assert_eq!(bits.get_sign_magnitude_field::<i8>(16..24), Ok(0));
+    assert_eq!(bits.get_sign_magnitude_field::<i8>(4..8), Ok(5));
+    assert_eq!(bits.get_ones_complement_field::<i8>(8..16), Ok(-5));
+    assert_eq!(bits.get_ones_complement_field::<i8>(4..8), Ok(5));
+    assert_eq!(bits.get_ones_complement_field::<i8>(8..12), Ok(0));
+}
+
+#[test]
+fn signed_int_conversion() {
+    let bits: BitSlice<_> = (-3i16).into();
+    assert_eq!(bits, BitSlice::<_>::from(0xfffdu16));
+    assert_eq!(i16::try_from(bits.clone()), Ok(-3));
+    assert_eq!(i8::try_from(bits.clone()), Ok(-3));
+    assert_eq!(i64::try_from(bits), Ok(-3));
+    let bits: BitSlice<_> = i32::MIN.into();
+    assert_eq!(i16::try_from(bits.clone()), Err(Error::ConversionError));
+    assert_eq!(i32::try_from(bits), Ok(i32::MIN));
+}
+
+fn check_indices_and_runs<B: BitOrder + Default, E: ByteOrder + Default>() {
+    let data: [u8; 20] = core::array::from_fn(|i| match i % 5 {
+        0 => 0,
+        1 => 0xff,
+        _ => (i * 53) as u8,
+    });
+    let full: BitSlice<&[u8], B, E> = data.as_ref().into();
+    for range in [0..160, 3..150, 9..10, 17..17, 40..120] {
+        let slice = full.slice(range);
+        let ones: Vec<usize> = (0..slice.len()).filter(|&i| slice.get_bit(i)).collect();
+        let zeros: Vec<usize> = (0..slice.len()).filter(|&i| !slice.get_bit(i)).collect();
+        assert_eq!(slice.iter_ones().len(), ones.len());
+        assert!(slice.iter_ones().eq(ones.iter().copied()));
+        assert!(slice.iter_ones().rev().eq(ones.iter().rev().copied()));
+        assert!(slice.iter_zeros().eq(zeros.iter().copied()));
+        assert!(slice.iter_zeros().rev().eq(zeros.iter().rev().copied()));
+
+        let mut runs: Vec<(bool, Range<usize>)> = vec![];
+        for (i, bit) in slice.iter().enumerate() {
+            match runs.last_mut() {
+                Some((value, run)) if *value == bit => run.end = i + 1,
+                _ => runs.push((bit, i..i + 1)),
+            }
+        }
+        assert!(slice.runs().eq(runs.iter().cloned()));
+        assert!(slice.runs().rev().eq(runs.iter().rev().cloned()));
+    }
+}
+
+#[test]
+fn test_indices_and_runs() {
+    for_each_order!(check_indices_and_runs);
+}
+
+#[test]
+fn test_indices_from_both_ends() {
+    let x: BitSlice<_> = bits![1, 0, 1, 1, 0, 1, 0, 0, 1];
+    let mut ones = x.iter_ones();
+    assert_eq!(ones.next(), Some(0));
+    assert_eq!(ones.next_back(), Some(8));
+    assert_eq!(ones.next(), Some(2));
+    assert_eq!(ones.len(), 2);
+    assert_eq!(ones.next_back(), Some(5));
+    assert_eq!(ones.next_back(), Some(3));
+    assert_eq!(ones.next(), None);
+    assert_eq!(ones.next_back(), None);
+
+    let mut runs = x.runs();
+    assert_eq!(runs.next(), Some((true, 0..1)));
+    assert_eq!(runs.next_back(), Some((true, 8..9)));
+    assert_eq!(runs.next_back(), Some((false, 6..8)));
+    assert_eq!(runs.next(), Some((false, 1..2)));
+    assert_eq!(runs.next(), Some((true, 2..4)));
+    assert_eq!(runs.next(), Some((false, 4..5)));
+    assert_eq!(runs.next_back(), Some((true, 5..6)));
+    assert_eq!(runs.next(), None);
+}
+
+#[test]
+fn test_iter_double_ended() {
+    let x: BitSlice<_, Msb0, LittleEndian> = [0b1100_0000u8, 0b0000_0011, 0x80].as_ref().into();
+    let mut bits = x.iter();
+    assert_eq!(bits.next_back(), Some(false));
+    assert_eq!(bits.next(), Some(true));
+    assert_eq!(bits.len(), 22);
+    assert_eq!(bits.nth_back(6), Some(true));
+    assert_eq!(bits.next_back(), Some(true));
+    assert_eq!(bits.next_back(), Some(true));
+    assert_eq!(bits.len(), 13);
+    assert_eq!(bits.next(), Some(true));
+    assert_eq!(bits.len(), 12);
+    assert!(bits.rev().eq([false; 12]));
+
+    let y: BitSlice<_, Lsb0, BigEndian> = 0x8000_0000_0000_0000_0000_0001u128.into();
+    assert!(y
+        .iter()
+        .rev()
+        .eq(y.iter().collect::<Vec<_>>().into_iter().rev()));
+    assert_eq!(y.iter().last(), Some(false));
+    assert_eq!(y.slice(..96).iter().last(), Some(true));
+}
+
+#[test]
+fn test_iter_nth_past_end() {
+    let x: BitSlice<_> = bits![1, 0, 1];
+    let mut bits = x.iter();
+    assert_eq!(bits.nth(5), None);
+    assert_eq!(bits.len(), 0);
+    assert_eq!(bits.next(), None);
+    let mut bits = x.iter();
+    assert_eq!(bits.nth_back(3), None);
+    assert_eq!(bits.next(), None);
+}
+
+fn check_iter_mut<B: BitOrder + Default, E: ByteOrder + Default>() {
+    let original: [u8; 10] = core::array::from_fn(|i| (i * 41 + 3) as u8);
+    let reference: BitSlice<&[u8], B, E> = original.as_ref().into();
+    let expected = |i: usize| reference.get_bit(i + 5) ^ i.is_multiple_of(3);
+
+    let mut data = original;
+    let mut bits: BitSlice<&mut [u8], B, E> = data.as_mut().into();
+    bits.range = 5..75;
+    // All proxies are alive at the same time, several of them pointing into the same byte.
+    let mut all: Vec<BitMut> = bits.iter_mut().collect();
+    for (i, bit) in all.iter_mut().enumerate() {
+        let value = **bit;
+        bit.set(value ^ i.is_multiple_of(3));
+    }
+    drop(all);
+    assert!((0..70).all(|i| bits.get_bit(i) == expected(i)));
+
+    let mut data = original;
+    let mut bits: BitSlice<&mut [u8], B, E> = data.as_mut().into();
+    bits.range = 5..75;
+    for (i, mut bit) in bits.iter_mut().rev().enumerate() {
+        *bit ^= (69 - i).is_multiple_of(3);
+    }
+    assert!((0..70).all(|i| bits.get_bit(i) == expected(i)));
+
+    let mut data = original;
+    let mut bits: BitSlice<&mut [u8], B, E> = data.as_mut().into();
+    bits.range = 5..75;
+    bits.for_each_mut(|i, bit| *bit ^= i.is_multiple_of(3));
+    assert!((0..70).all(|i| bits.get_bit(i) == expected(i)));
+    // Bits outside of the slice are not modified.
+    let result: BitSlice<&[u8], B, E> = data.as_ref().into();
+    assert!(result.slice(..5) == reference.slice(..5));
+    assert!(result.slice(75..) == reference.slice(75..));
+}
+
+#[test]
+fn test_iter_mut() {
+    for_each_order!(check_iter_mut);
+    check_iter_mut::<Lsb0, Words<u16>>();
+    check_iter_mut::<Msb0, Words<u16>>();
+
+    let mut x: BitSlice<_> = bits![1, 0, 1];
+    {
+        let mut bits = x.iter_mut();
+        assert_eq!(bits.len(), 3);
+        assert!(!bits.nth(1).unwrap().replace(true));
+        assert!(bits.next_back().is_some());
+        assert!(bits.next().is_none());
+    }
+    assert!(x == bits![1, 1, 1]);
+}
+
+#[test]
+fn test_split_at_mut() {
+    let mut data = [0u8; 3];
+    let mut bits: BitSlice<_, Lsb0, LittleEndian> = data.as_mut().into();
+    let mut part = bits.slice_mut(5..20);
+    // The parts share the byte holding bits 8 to 15.
+    let (mut head, mut tail) = part.split_at_mut(6);
+    assert_eq!((head.len(), tail.len()), (6, 9));
+    head.set_field(.., 0b11_1111u8).unwrap();
+    tail.set_field(..4, 0b1010u8).unwrap();
+    tail.set_bit(8, true);
+    head.set_bit(5, false);
+    let (first, mut rest) = tail.split_at_mut(2);
+    assert_eq!(first.get_field::<u8>(..), Ok(0b10));
+    rest.set_bit(0, true);
+    let (empty, rest) = rest.split_at_mut(0);
+    assert!(empty.is_empty() && rest.len() == 7);
+    assert!(head
+        .iter_mut()
+        .map(|bit| *bit)
+        .eq([true, true, true, true, true, false]));
+    assert_eq!(data, [0xe0, 0x73, 0x08]);
+
+    let x: BitSlice<_> = bits![1, 0, 1, 1];
+    let (a, b) = x.split_at(1);
+    assert!(a == bits![1]);
+    assert!(b == bits![0, 1, 1]);
+}
+
+#[test]
+fn test_split_at_mut_aligned() {
+    let mut data = [0u8; 4];
+    let mut bits: BitSlice<_, Lsb0, LittleEndian> = data.as_mut().into();
+    let mut part = bits.slice_mut(5..30);
+    let (mut head, mut tail) = part.split_at_mut_aligned(11);
+    head.invert();
+    tail.for_each_mut(|i, bit| *bit = i % 3 == 0);
+    let (mut a, b) = tail.split_at_mut_aligned(8);
+    a.set_bit(1, true);
+    assert_eq!(b.len(), 6);
+    assert_eq!(
+        tail.try_split_at_mut_aligned(9).err(),
+        Some(Error::Unaligned {
+            index: 9,
+            alignment: 8
+        })
+    );
+    assert_eq!(data, [0xe0, 0xff, 0x4b, 0x12]);
+}
+
+#[test]
+#[should_panic(expected = "bit index 17 out of bounds for length 16")]
+fn test_split_at_mut_out_of_bounds() {
+    let mut data = [0u8; 2];
+    let mut bits: BitSlice<_> = data.as_mut().into();
+    let _ = bits.split_at_mut(17);
+}
+
+#[test]
+fn test_words() {
+    // With `Lsb0` bit `n` is bit `n % 32` of word `n / 32`.
+    let mut words = [0u32; 3];
+    let mut bits: BitSlice<_, Lsb0, Words<u32>> = BitSlice::from_words_mut(&mut words);
+    bits.set_field(28..40, 0xabcu16).unwrap();
+    bits.set_bit(95, true);
+    assert_eq!(words, [0xc000_0000, 0x0000_00ab, 0x8000_0000]);
+
+    // With `Msb0` the bits of each word are counted from its most significant bit.
+    let mut words = [0u16; 2];
+    let mut bits: BitSlice<_, Msb0, Words<u16>> = BitSlice::from_words_mut(&mut words);
+    bits.set_field(12..20, 0xa5u8).unwrap();
+    assert_eq!(bits.get_field::<u8>(12..20), Ok(0xa5));
+    assert_eq!(words, [0x000a, 0x5000]);
+
+    let words: [u64; 3] =
+        core::array::from_fn(|i| 0x0123_4567_89ab_cdefu64.rotate_left(i as u32 * 7));
+    let msb: BitSlice<_, Msb0, Words<u64>> = BitSlice::from_words(&words);
+    let lsb: BitSlice<_, Lsb0, Words<u64>> = BitSlice::from_words(&words);
+    for n in 0..192 {
+        assert_eq!(msb.get_bit(n), words[n / 64] >> (63 - n % 64) & 1 == 1);
+        assert_eq!(lsb.get_bit(n), words[n / 64] >> (n % 64) & 1 == 1);
+    }
+    let ones: usize = words.iter().map(|w| w.count_ones() as usize).sum();
+    assert_eq!(msb.count_ones(), ones);
+    assert_eq!(
+        msb.slice(8..).count_ones(),
+        ones - (words[0] >> 56).count_ones() as usize
+    );
+    assert_eq!(
+        msb.get_field::<u64>(60..124),
+        Ok(words[0] << 60 | words[1] >> 4)
+    );
+    assert_eq!(
+        lsb.get_field::<u64>(60..124),
+        Ok(words[0] >> 60 | words[1] << 4)
+    );
+
+    let mut copy = words;
+    let mut bits: BitSlice<_, Msb0, Words<u64>> = BitSlice::from_words_mut(&mut copy);
+    bits.slice_mut(64..).invert();
+    bits.slice_mut(..64).rotate_left(4);
+    assert_eq!(copy, [words[0].rotate_left(4), !words[1], !words[2]]);
+
+    let mut bits: BitSlice<_, Msb0, Words<u64>> = BitSlice::from_words_mut(&mut copy);
+    let (mut head, mut tail) = bits.split_at_mut(8);
+    head.set_field(.., 0u8).unwrap();
+    tail.set_field(..8, 0xffu8).unwrap();
+    let (head, tail) = bits.split_at_mut(64);
+    assert_eq!((head.len(), tail.len()), (64, 128));
+    assert_eq!(
+        copy[0],
+        words[0].rotate_left(4) & 0x0000_ffff_ffff_ffff | 0x00ff_0000_0000_0000
+    );
+
+    // Aligned splits must start a word.
+    let mut bits: BitSlice<_, Msb0, Words<u64>> = BitSlice::from_words_mut(&mut copy);
+    assert_eq!(
+        bits.try_split_at_mut_aligned(8).err(),
+        Some(Error::Unaligned {
+            index: 8,
+            alignment: 64
+        })
+    );
+    let (_, mut tail) = bits.split_at_mut_aligned(64);
+    tail.invert();
+    assert_eq!(copy[1..], [words[1], words[2]]);
+
+    // The storage must consist of whole words.
+    let bytes = [0u8; 6];
+    assert_eq!(
+        BitSlice::<&[u8], Msb0, Words<u32>>::try_new(&bytes[..3], 24).err(),
+        Some(Error::PartialWord {
+            len: 3,
+            word_bytes: 4
+        })
+    );
+    assert_eq!(
+        BitSlice::<&[u8], Lsb0, Words<u16>>::try_new(&bytes[..5], 8).err(),
+        Some(Error::PartialWord {
+            len: 5,
+            word_bytes: 2
+        })
+    );
+    assert!(BitSlice::<&[u8], Lsb0, Words<u16>>::try_new(&bytes[..4], 8).is_ok());
+    assert_eq!(
+        BitWriter::try_new_with_order([0u8; 6], Msb0, Words::<u32>::default()).err(),
+        Some(Error::PartialWord {
+            len: 6,
+            word_bytes: 4
+        })
+    );
+    assert!(BitWriter::try_new_with_order([0u8; 8], Msb0, Words::<u32>::default()).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "does not consist of whole words of 4 bytes")]
+fn test_words_partial() {
+    let _ = BitSlice::<&[u8], Msb0, Words<u32>>::from(&[0u8; 3][..]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_words_growing() {
+    let mut vec: BitVec<Msb0, Words<u32>> = BitVec::new();
+    vec.extend([true, false, true]);
+    assert_eq!(vec.as_bytes(), 0xa000_0000u32.to_ne_bytes());
+    vec.extend(core::iter::repeat_n(true, 32));
+    assert_eq!(vec.as_bytes().len(), 8);
+    assert_eq!(
+        vec.as_bitslice().get_field::<u64>(..35),
+        Ok(0b101 << 32 | 0xffff_ffff)
+    );
+
+    let mut writer: BitWriter<_, Lsb0, Words<u16>> = BitWriter::new(Vec::new());
+    writer.write(0x5u8, 3).unwrap();
+    assert_eq!(writer.into_inner(), 0x0005u16.to_ne_bytes());
+}
+
+#[test]
+fn test_word_storage() {
+    // Arrays and slices of wider words number their bits word by word, like `Words`.
+    let mut regs = [0u32; 3];
+    let mut bits: BitSlice<_, Lsb0, LittleEndian> = BitSlice::new(&mut regs, 96);
+    bits.set_field(28..40, 0xabcu16).unwrap();
+    bits.set_bit(95, true);
+    assert_eq!(regs, [0xc000_0000, 0x0000_00ab, 0x8000_0000]);
+
+    let words: [u64; 3] =
+        core::array::from_fn(|i| 0x0123_4567_89ab_cdefu64.rotate_left(i as u32 * 7));
+    let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_ne_bytes()).collect();
+    let array: BitSlice<[u64; 3], Msb0, LittleEndian> = BitSlice::new(words, 192);
+    let bytes: BitSlice<_, Msb0, Words<u64>> = bytes.as_slice().into();
+    assert!(array.iter().eq(bytes.iter()));
+    assert_eq!(array.count_ones(), bytes.count_ones());
+    assert_eq!(
+        array.get_field::<u64>(60..124),
+        Ok(words[0] << 60 | words[1] >> 4)
+    );
+    for n in 0..192 {
+        assert_eq!(array.get_bit(n), words[n / 64] >> (63 - n % 64) & 1 == 1);
+    }
+
+    // With `BigEndian` the first bits are in the last word.
+    let mut regs = [0u16; 2];
+    let mut bits: BitSlice<_, Lsb0, BigEndian> = BitSlice::from_words_mut(&mut regs);
+    bits.set_field(12..20, 0xa5u8).unwrap();
+    assert_eq!(bits.get_field::<u8>(12..20), Ok(0xa5));
+    assert_eq!(regs, [0x000a, 0x5000]);
+
+    // Splits of a `&mut [u64]` keep the word type, and aligned ones split its words.
+    let mut copy = words;
+    let mut bits: BitSlice<&mut [u64], Msb0, LittleEndian> = BitSlice::from_words_mut(&mut copy);
+    let (mut head, mut tail) = bits.split_at_mut(8);
+    head.set_field(.., 0u8).unwrap();
+    tail.set_field(..8, 0xffu8).unwrap();
+    assert_eq!(
+        bits.try_split_at_mut_aligned(8).err(),
+        Some(Error::Unaligned {
+            index: 8,
+            alignment: 64
+        })
+    );
+    let (_, mut tail): (_, BitSlice<&mut [u64], Msb0, LittleEndian>) =
+        bits.split_at_mut_aligned(64);
+    tail.invert();
+    assert_eq!(
+        copy,
+        [
+            words[0] & 0x0000_ffff_ffff_ffff | 0x00ff_0000_0000_0000,
+            !words[1],
+            !words[2]
+        ]
+    );
+
+    // The storage must consist of whole words of the byte order.
+    assert_eq!(
+        BitSlice::<&[u32], Lsb0, Words<u64>>::try_new(&[0u32; 3], 0).err(),
+        Some(Error::PartialWord {
+            len: 12,
+            word_bytes: 8
+        })
+    );
+    let regs = [0x1234u16, 0x5678, 0x9abc, 0xdef0];
+    let bits: BitSlice<_, Lsb0, Words<u64>> = BitSlice::from_words(&regs);
+    #[cfg(target_endian = "little")]
+    assert_eq!(bits.get_field::<u64>(..), Ok(0xdef0_9abc_5678_1234));
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut bits: BitSlice<_, Msb0, LittleEndian> = BitSlice::new(vec![0u32, 1], 64);
+        bits.set_bit(0, true);
+        assert_eq!(bits.iter_ones().collect::<Vec<_>>(), [0, 63]);
+    }
+}
+
+#[test]
+fn test_checked_api() {
+    let mut data = [0u8; 2];
+    assert_eq!(
+        BitSlice::<_>::try_new(data.as_ref(), 17).err(),
+        Some(Error::StorageTooSmall {
+            bits: 17,
+            capacity: 16
+        })
+    );
+    let mut bits: BitSlice<_> = BitSlice::try_new(data.as_mut(), 12).unwrap();
+    assert_eq!(bits.get(11), Some(false));
+    assert_eq!(bits.get(12), None);
+    assert_eq!(
+        bits.try_set_bit(12, true),
+        Err(Error::IndexOutOfBounds { index: 12, len: 12 })
+    );
+    assert_eq!(bits.try_set_bit(11, true), Ok(()));
+    assert_eq!(bits.get(11), Some(true));
+
+    let err = Error::RangeOutOfBounds {
+        start: 4,
+        end: 13,
+        len: 12,
+    };
+    assert_eq!(bits.try_slice(4..13).err(), Some(err));
+    assert_eq!(bits.try_slice_mut(4..=12).err(), Some(err));
+    assert_eq!(bits.get_field::<u16>(4..13), Err(err));
+    assert_eq!(bits.set_field(4..13, 0u16), Err(err));
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = bits.try_slice(5..4).err();
+    assert_eq!(
+        reversed,
+        Some(Error::RangeOutOfBounds {
+            start: 5,
+            end: 4,
+            len: 12
+        })
+    );
+    assert_eq!(bits.try_slice(4..12).map(|s| s.len()), Ok(8));
+    // Inclusive and excluded bounds at `usize::MAX` do not overflow.
+    assert_eq!(
+        bits.try_slice(..=usize::MAX).err(),
+        Some(Error::RangeOutOfBounds {
+            start: 0,
+            end: usize::MAX,
+            len: 12
+        })
+    );
+    let excluded = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+    assert!(bits.get_field::<u8>(excluded).is_err());
+    assert!(bits.set_field(usize::MAX..=usize::MAX, 0u8).is_err());
+
+    let mut other: BitSlice<_> = bits![1, 0, 1];
+    assert_eq!(
+        other.try_xor_assign(&bits.slice(..4)),
+        Err(Error::LengthMismatch { left: 3, right: 4 })
+    );
+    assert_eq!(other.try_or_assign(&bits.slice(..3)), Ok(()));
+
+    assert!(bits.try_split_at(13).is_err());
+    assert!(bits.try_split_at_mut(3).is_ok());
+    assert_eq!(
+        bits.try_split_at_mut(13).err(),
+        Some(Error::IndexOutOfBounds { index: 13, len: 12 })
+    );
+    assert!(bits.try_split_at_mut(8).is_ok());
+    assert!(bits.try_split_at_mut_aligned(8).is_ok());
+    assert_eq!(
+        bits.try_split_at_mut_aligned(13).err(),
+        Some(Error::IndexOutOfBounds { index: 13, len: 12 })
+    );
+    assert_eq!(
+        bits.try_split_at_mut_aligned(3).err(),
+        Some(Error::Unaligned {
+            index: 3,
+            alignment: 8
+        })
+    );
+}
+
+#[test]
+fn test_error_display() {
+    use alloc::string::ToString;
+    assert_eq!(
+        Error::IndexOutOfBounds { index: 8, len: 8 }.to_string(),
+        "bit index 8 out of bounds for length 8"
+    );
+    assert_eq!(
+        Error::RangeOutOfBounds {
+            start: 2,
+            end: 9,
+            len: 8
+        }
+        .to_string(),
+        "bit range 2..9 out of bounds for length 8"
+    );
+    assert_eq!(
+        Error::InvalidDigit {
+            index: 3,
+            character: '2'
+        }
+        .to_string(),
+        "invalid digit '2' at offset 3"
+    );
+    assert_eq!(
+        Error::Unaligned {
+            index: 3,
+            alignment: 8
+        }
+        .to_string(),
+        "bit index 3 does not start a word of 8 bits"
+    );
+    assert_eq!(
+        Error::PartialWord {
+            len: 3,
+            word_bytes: 4
+        }
+        .to_string(),
+        "a storage of 3 bytes does not consist of whole words of 4 bytes"
+    );
+}
+
+#[test]
+#[should_panic(expected = "bit index 3 out of bounds for length 3")]
+fn test_get_bit_out_of_bounds() {
+    let bits: BitSlice<_> = bits![1, 0, 1];
+    bits.get_bit(3);
+}
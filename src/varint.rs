@@ -0,0 +1,303 @@
+use crate::{BitOrder, BitReader, BitStorage, BitWriter, ByteOrder, Error, WriteStorage};
+
+/// The maximum number of 7-bit groups of a 64-bit varint.
+const MAX_GROUPS: usize = 10;
+
+/// Splits `value` into LEB128 groups and returns them together with their number.
+///
+/// `signed` decides whether the last group must carry the sign of the value in its bit 6.
+fn encode(value: i128, signed: bool) -> ([u8; MAX_GROUPS], usize) {
+    let mut groups = [0u8; MAX_GROUPS];
+    let mut value = value;
+    let mut n = 0;
+    loop {
+        let group = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = if signed {
+            (value == 0 && group & 0x40 == 0) || (value == -1 && group & 0x40 != 0)
+        } else {
+            value == 0
+        };
+        groups[n] = if done { group } else { group | 0x80 };
+        n += 1;
+        if done {
+            return (groups, n);
+        }
+    }
+}
+
+/// LEB128 and protobuf varints.
+///
+/// Each 8-bit group is read like [`BitReader::read`] reads a `u8`, so with
+/// [`LittleEndian`](crate::LittleEndian) and a byte-aligned position the groups are the bytes of
+/// the storage in either bit order. Varints can also start at any other bit, for example inside a
+/// bit-packed frame.
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> BitReader<S, B, Endian> {
+    /// Reads the groups of a LEB128 varint and returns its payload and length in bits.
+    fn peek_leb128(&self, signed: bool) -> Result<(u64, usize), Error> {
+        let mut value = 0u64;
+        let mut pos = self.pos;
+        for i in 0..MAX_GROUPS {
+            let range = self.take(pos - self.pos + 8)?;
+            let group: u8 = self.slice.get_field(range.end - 8..range.end)?;
+            pos = range.end;
+            value |= ((group & 0x7f) as u64) << (7 * i);
+            if group & 0x80 == 0 {
+                if i == MAX_GROUPS - 1 {
+                    // Only bit 63 is left, the other bits must be zero or extend its sign.
+                    let valid = if signed {
+                        group == 0 || group == 0x7f
+                    } else {
+                        group <= 1
+                    };
+                    if !valid {
+                        return Err(Error::ConversionError);
+                    }
+                } else if signed && group & 0x40 != 0 {
+                    value |= u64::MAX << (7 * (i + 1));
+                }
+                return Ok((value, pos - self.pos));
+            }
+        }
+        Err(Error::ConversionError)
+    }
+    /// Reads an unsigned LEB128 varint, which is also the varint of protobuf.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if the varint is cut off or [`Error::ConversionError`] if
+    /// it does not fit into a `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitReader, BitSlice, LittleEndian, Lsb0};
+    /// let data = [0xe5u8, 0x8e, 0x26, 0x7f];
+    /// let bits: BitSlice<_, Lsb0, LittleEndian> = data.as_ref().into();
+    /// let mut reader = BitReader::new(bits);
+    /// assert_eq!(reader.read_uleb128(), Ok(624485));
+    /// assert_eq!(reader.read_sleb128(), Ok(-1));
+    /// ```
+    pub fn read_uleb128(&mut self) -> Result<u64, Error> {
+        let (value, len) = self.peek_leb128(false)?;
+        self.pos += len;
+        Ok(value)
+    }
+    /// Reads a signed LEB128 varint.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if the varint is cut off or [`Error::ConversionError`] if
+    /// it does not fit into an `i64`.
+    pub fn read_sleb128(&mut self) -> Result<i64, Error> {
+        let (value, len) = self.peek_leb128(true)?;
+        self.pos += len;
+        Ok(value as i64)
+    }
+    /// Reads a zigzag encoded varint, as used for the `sint32` and `sint64` types of protobuf.
+    ///
+    /// # Returns
+    /// The value, [`Error::EndOfData`] if the varint is cut off or [`Error::ConversionError`] if
+    /// it does not fit into an `i64`.
+    pub fn read_zigzag(&mut self) -> Result<i64, Error> {
+        let value = self.read_uleb128()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+}
+
+/// LEB128 and protobuf varints.
+///
+/// These write the varints read by the corresponding methods of [`BitReader`], always in their
+/// shortest form.
+impl<S: WriteStorage, B: BitOrder, Endian: ByteOrder> BitWriter<S, B, Endian> {
+    /// Writes the groups of a LEB128 varint.
+    fn write_leb128(&mut self, value: i128, signed: bool) -> Result<(), Error> {
+        let (groups, n) = encode(value, signed);
+        let pos = self.pos;
+        let mut slice = self.reserve(n * 8)?;
+        for (i, &group) in groups[..n].iter().enumerate() {
+            slice.set_field(pos + i * 8..pos + i * 8 + 8, group)?;
+        }
+        self.pos += n * 8;
+        Ok(())
+    }
+    /// Writes `value` as an unsigned LEB128 varint, which is also the varint of protobuf.
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has too few bits left for the varint.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitWriter, LittleEndian, Lsb0};
+    /// let mut writer: BitWriter<_, Lsb0, LittleEndian> = BitWriter::new([0u8; 4]);
+    /// writer.write_uleb128(624485).unwrap();
+    /// writer.write_sleb128(-1).unwrap();
+    /// assert_eq!(writer.into_inner(), [0xe5, 0x8e, 0x26, 0x7f]);
+    /// ```
+    pub fn write_uleb128(&mut self, value: u64) -> Result<(), Error> {
+        self.write_leb128(value as i128, false)
+    }
+    /// Writes `value` as a signed LEB128 varint.
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has too few bits left for the varint.
+    pub fn write_sleb128(&mut self, value: i64) -> Result<(), Error> {
+        self.write_leb128(value as i128, true)
+    }
+    /// Writes `value` as a zigzag encoded varint, as used for the `sint32` and `sint64` types of
+    /// protobuf.
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has too few bits left for the varint.
+    pub fn write_zigzag(&mut self, value: i64) -> Result<(), Error> {
+        self.write_uleb128(((value << 1) ^ (value >> 63)) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    extern crate alloc;
+    extern crate std;
+
+    /// Unsigned values and their LEB128 bytes.
+    const UNSIGNED: [(u64, &[u8]); 6] = [
+        (0, &[0x00]),
+        (127, &[0x7f]),
+        (128, &[0x80, 0x01]),
+        (300, &[0xac, 0x02]),
+        (624485, &[0xe5, 0x8e, 0x26]),
+        (
+            u64::MAX,
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+        ),
+    ];
+    /// Signed values and their LEB128 bytes.
+    const SIGNED: [(i64, &[u8]); 7] = [
+        (0, &[0x00]),
+        (63, &[0x3f]),
+        (64, &[0xc0, 0x00]),
+        (-64, &[0x40]),
+        (-65, &[0xbf, 0x7f]),
+        (-123456, &[0xc0, 0xbb, 0x78]),
+        (
+            i64::MIN,
+            &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f],
+        ),
+    ];
+    /// Signed values and their zigzag encoded protobuf varints.
+    const ZIGZAG: [(i64, &[u8]); 5] = [
+        (0, &[0x00]),
+        (-1, &[0x01]),
+        (1, &[0x02]),
+        (-2, &[0x03]),
+        (
+            i64::MIN,
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+        ),
+    ];
+
+    #[test]
+    fn test_vectors() {
+        for (value, bytes) in UNSIGNED {
+            let mut data = [0u8; 10];
+            let mut writer: BitWriter<_> = BitWriter::new(data.as_mut());
+            writer.write_uleb128(value).unwrap();
+            let len = writer.finish();
+            assert_eq!(&data[..len], bytes);
+            let mut reader: BitReader<_, Msb0, LittleEndian> = BitSlice::from(bytes).into();
+            assert_eq!(reader.read_uleb128(), Ok(value));
+            assert_eq!(reader.remaining(), 0);
+        }
+        for (value, bytes) in SIGNED {
+            let mut data = [0u8; 10];
+            let mut writer: BitWriter<_> = BitWriter::new(data.as_mut());
+            writer.write_sleb128(value).unwrap();
+            let len = writer.finish();
+            assert_eq!(&data[..len], bytes);
+            let mut reader: BitReader<_> = BitSlice::from(bytes).into();
+            assert_eq!(reader.read_sleb128(), Ok(value));
+        }
+        for (value, bytes) in ZIGZAG {
+            let mut data = [0u8; 10];
+            let mut writer: BitWriter<_> = BitWriter::new(data.as_mut());
+            writer.write_zigzag(value).unwrap();
+            let len = writer.finish();
+            assert_eq!(&data[..len], bytes);
+            let mut reader: BitReader<_> = BitSlice::from(bytes).into();
+            assert_eq!(reader.read_zigzag(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_unaligned() {
+        // The bytes of a varint are written like 8-bit fields, at any bit position.
+        let mut writer: BitWriter<_, Lsb0, LittleEndian> = BitWriter::new([0u8; 3]);
+        writer.write_bit(true).unwrap();
+        writer.write_uleb128(300).unwrap();
+        assert_eq!(writer.into_inner(), [0x59, 0x05, 0x00]);
+        let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new([0u8; 3]);
+        writer.write_bit(true).unwrap();
+        writer.write_uleb128(300).unwrap();
+        assert_eq!(writer.into_inner(), [0xd6, 0x01, 0x00]);
+
+        let mut writer: BitWriter<_> = BitWriter::new([0u8; 128]);
+        writer.write(0b101u8, 3).unwrap();
+        for (value, _) in UNSIGNED {
+            writer.write_uleb128(value).unwrap();
+            writer.write_bit(true).unwrap();
+        }
+        for (value, _) in SIGNED {
+            writer.write_sleb128(value).unwrap();
+            writer.write_zigzag(value).unwrap();
+        }
+        let len = writer.position();
+        let data = writer.into_inner();
+
+        let mut reader: BitReader<_> = BitSlice::new(data.as_slice(), len).into();
+        assert_eq!(reader.read::<u8>(3), Ok(0b101));
+        for (value, bytes) in UNSIGNED {
+            let pos = reader.position();
+            assert_eq!(reader.read_uleb128(), Ok(value));
+            assert_eq!(reader.position(), pos + bytes.len() * 8);
+            assert_eq!(reader.read_bit(), Ok(true));
+        }
+        for (value, _) in SIGNED {
+            assert_eq!(reader.read_sleb128(), Ok(value));
+            assert_eq!(reader.read_zigzag(), Ok(value));
+        }
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        let too_long = [0x80u8; 11];
+        let mut reader: BitReader<_> = BitSlice::from(too_long.as_ref()).into();
+        assert_eq!(reader.read_uleb128(), Err(Error::ConversionError));
+        let too_large = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        let mut reader: BitReader<_> = BitSlice::from(too_large.as_ref()).into();
+        assert_eq!(reader.read_uleb128(), Err(Error::ConversionError));
+        assert_eq!(reader.read_sleb128(), Err(Error::ConversionError));
+        assert_eq!(reader.position(), 0);
+
+        let cut_off = [0xffu8, 0xff];
+        let mut reader: BitReader<_> = BitSlice::from(cut_off.as_ref()).into();
+        reader.skip(1).unwrap();
+        assert_eq!(
+            reader.read_uleb128(),
+            Err(Error::EndOfData {
+                requested: 16,
+                remaining: 15
+            })
+        );
+        assert_eq!(reader.position(), 1);
+
+        let mut writer: BitWriter<_> = BitWriter::new([0u8; 2]);
+        writer.write_bit(true).unwrap();
+        assert_eq!(
+            writer.write_uleb128(300),
+            Err(Error::EndOfData {
+                requested: 16,
+                remaining: 15
+            })
+        );
+        assert_eq!(writer.position(), 1);
+    }
+}
@@ -0,0 +1,645 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{BitOrder, BitSlice, BitStorage, ByteOrder, Error, LittleEndian, Lsb0};
+
+/// An owned, growable sequence of bits backed by a `Vec<u8>`.
+///
+/// The bits are laid out in the same way as in a [`BitSlice`] with the same bit and byte order,
+/// so [`BitVec::as_bitslice`] and [`BitVec::as_mut_bitslice`] give access to the full
+/// [`BitSlice`] API. Bits past the end of the vector are always zero.
+///
+/// # Type Parameters
+/// - `B`: The bit order, which dictates the order in which bits are read.
+/// - `Endian`: The byte order, which dictates the order in which bytes are read.
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BitVec, Msb0, LittleEndian};
+/// let mut bits: BitVec<Msb0, LittleEndian> = BitVec::new();
+/// bits.extend([true, false, true]);
+/// bits.insert(1, true);
+/// assert_eq!(bits.as_bitslice().get_field::<u8>(..), Ok(0b1101));
+/// assert_eq!(bits.as_bytes(), &[0b1101_0000]);
+/// ```
+pub struct BitVec<B = Lsb0, Endian = LittleEndian> {
+    /// The storage. Depending on the byte order the used bytes are at its start or its end.
+    bytes: Vec<u8>,
+    len: usize,
+    bit_order: B,
+    byte_order: Endian,
+}
+
+impl<B: BitOrder, Endian: ByteOrder> BitVec<B, Endian> {
+    /// Creates a new, empty [BitVec] with default bit and byte order.
+    #[inline(always)]
+    pub fn new() -> Self
+    where
+        B: Default,
+        Endian: Default,
+    {
+        Self::new_with_order(Default::default(), Default::default())
+    }
+    /// Creates a new, empty [BitVec] with specific bit and byte order.
+    ///
+    /// # Arguments
+    /// * `bit_order` - The bit order to use.
+    /// * `endianness` - The byte order to use.
+    #[inline(always)]
+    pub fn new_with_order(bit_order: B, endianness: Endian) -> Self {
+        Self {
+            bytes: Vec::new(),
+            len: 0,
+            bit_order,
+            byte_order: endianness,
+        }
+    }
+    /// Creates a new, empty [BitVec] with room for at least `capacity` bits.
+    pub fn with_capacity(capacity: usize) -> Self
+    where
+        B: Default,
+        Endian: Default,
+    {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+        vec
+    }
+    /// Returns the number of bits in the vector.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns `true` if the vector contains no bits.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Returns the number of bits the vector can hold without reallocating.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.bytes.len() * 8
+    }
+    /// Returns the storage indices of the first `num_bytes` bytes in byte order, rounded up to
+    /// whole words.
+    fn storage_range(&self, num_bytes: usize) -> Range<usize> {
+        let total = self.bytes.len();
+        let num_bytes = num_bytes.next_multiple_of(self.byte_order.word_bytes());
+        if !self.byte_order.starts_at_end() {
+            0..num_bytes
+        } else {
+            total - num_bytes..total
+        }
+    }
+    /// Returns the bytes holding the bits of the vector.
+    ///
+    /// The bytes are laid out according to the bit and byte order. Unused bits of the last byte
+    /// are zero, and so are the unused bytes of the last word with [`Words`](crate::Words).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[self.storage_range(self.len.div_ceil(8))]
+    }
+    /// Returns a [BitSlice] over all bits of the vector.
+    pub fn as_bitslice(&self) -> BitSlice<&[u8], B, Endian> {
+        BitSlice::new_with_order(self.as_bytes(), self.len, self.bit_order, self.byte_order)
+    }
+    /// Returns a mutable [BitSlice] over all bits of the vector.
+    pub fn as_mut_bitslice(&mut self) -> BitSlice<&mut [u8], B, Endian> {
+        let range = self.storage_range(self.len.div_ceil(8));
+        BitSlice::new_with_order(
+            &mut self.bytes[range],
+            self.len,
+            self.bit_order,
+            self.byte_order,
+        )
+    }
+    /// Retrieves the value of a bit at a specified index.
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds. See [`BitVec::get`] for a non-panicking version.
+    #[inline(always)]
+    #[track_caller]
+    pub fn get_bit(&self, n: usize) -> bool {
+        self.as_bitslice().get_bit(n)
+    }
+    /// Retrieves the value of a bit at a specified index, or `None` if `n` is out of bounds.
+    #[inline(always)]
+    pub fn get(&self, n: usize) -> Option<bool> {
+        self.as_bitslice().get(n)
+    }
+    /// Sets the value of a bit at a specified index.
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds. See [`BitVec::try_set_bit`] for a non-panicking version.
+    #[inline(always)]
+    #[track_caller]
+    pub fn set_bit(&mut self, n: usize, value: bool) {
+        self.as_mut_bitslice().set_bit(n, value)
+    }
+    /// Sets the value of a bit at a specified index, or returns [`Error::IndexOutOfBounds`] if
+    /// `n` is out of bounds.
+    ///
+    /// See [`BitVec::set_bit`].
+    #[inline(always)]
+    pub fn try_set_bit(&mut self, n: usize, value: bool) -> Result<(), Error> {
+        self.as_mut_bitslice().try_set_bit(n, value)
+    }
+    /// Reserves capacity for at least `additional` more bits.
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = (self.len + additional)
+            .div_ceil(8)
+            .next_multiple_of(self.byte_order.word_bytes());
+        if needed <= self.bytes.len() {
+            return;
+        }
+        let total = needed.max(self.bytes.len() * 2).max(8);
+        let used = self.len.div_ceil(8);
+        let mut bytes = alloc::vec![0; total];
+        // The position of a byte depends on the size of the storage for some byte orders, so the
+        // bytes are copied one word at a time by their logical position.
+        for first in (0..used).step_by(8) {
+            let count = (used - first).min(8);
+            let word = self
+                .byte_order
+                .load(self.bit_order, &self.bytes, first, count);
+            self.byte_order
+                .store(self.bit_order, &mut bytes, first, count, word);
+        }
+        self.bytes = bytes;
+    }
+    /// Appends a bit to the end of the vector.
+    pub fn push(&mut self, value: bool) {
+        self.reserve(1);
+        self.len += 1;
+        self.set_bit(self.len - 1, value);
+    }
+    /// Removes the last bit from the vector and returns it, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<bool> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.get_bit(self.len - 1);
+        self.set_bit(self.len - 1, false);
+        self.len -= 1;
+        Some(value)
+    }
+    /// Inserts a bit at position `index`, shifting all bits after it towards the end.
+    ///
+    /// # Panics
+    /// Panics if `index > len`. See [`BitVec::try_insert`] for a non-panicking version.
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: bool) {
+        self.try_insert(index, value)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Inserts a bit at position `index`, or returns [`Error::IndexOutOfBounds`] if
+    /// `index > len`.
+    ///
+    /// See [`BitVec::insert`].
+    pub fn try_insert(&mut self, index: usize, value: bool) -> Result<(), Error> {
+        if index > self.len {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+        self.reserve(1);
+        self.len += 1;
+        let mut slice = self.as_mut_bitslice();
+        slice.copy_bits_within(index..slice.len() - 1, index + 1);
+        slice.set_bit(index, value);
+        Ok(())
+    }
+    /// Removes and returns the bit at position `index`, shifting all bits after it towards the
+    /// start.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds. See [`BitVec::try_remove`] for a non-panicking
+    /// version.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> bool {
+        self.try_remove(index).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Removes and returns the bit at position `index`, or returns
+    /// [`Error::IndexOutOfBounds`] if `index` is out of bounds.
+    ///
+    /// See [`BitVec::remove`].
+    pub fn try_remove(&mut self, index: usize) -> Result<bool, Error> {
+        if index >= self.len {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+        let mut slice = self.as_mut_bitslice();
+        let value = slice.get_bit(index);
+        slice.copy_bits_within(index + 1..slice.len(), index);
+        slice.set_bit(slice.len() - 1, false);
+        self.len -= 1;
+        Ok(value)
+    }
+    /// Shortens the vector to `len` bits. Has no effect if the vector is already shorter.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let end = self.len;
+            self.as_mut_bitslice().fill_bits(len..end, false);
+            self.len = len;
+        }
+    }
+    /// Removes all bits from the vector, keeping its capacity.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+    /// Appends all bits of `other` to the end of the vector.
+    pub fn extend_from_bitslice<S: BitStorage>(&mut self, other: &BitSlice<S, B, Endian>) {
+        let start = self.len;
+        self.reserve(other.len());
+        self.len += other.len();
+        let mut slice = self.as_mut_bitslice();
+        let mut i = 0;
+        while i < other.len() {
+            let n = (other.len() - i).min(64);
+            slice.write_bits(start + i, n, other.read_bits(i, n));
+            i += n;
+        }
+    }
+    /// Moves all bits of `other` to the end of the vector, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.extend_from_bitslice(&other.as_bitslice());
+        other.clear();
+    }
+}
+
+/// Parsing from binary, octal and hexadecimal strings.
+///
+/// Each digit stands for 1, 3 or 4 bits, which are appended with the most significant bit of the
+/// digit first, so `"0xb2"` and `"1011_0010"` parse to the same bits in every bit order. This is
+/// the order in which [`BitSlice::to_string`] prints the bits. Digits may be separated by
+/// underscores and whitespace.
+///
+/// Errors report the byte offset of the offending character in the string.
+impl<B: BitOrder + Default, Endian: ByteOrder + Default> BitVec<B, Endian> {
+    /// Parses the digits of `s` in `radix` 2, 8 or 16, starting at byte `offset` of the original
+    /// string.
+    ///
+    /// With `num_bits`, the leading bits are dropped or zeros are prepended to get exactly
+    /// `num_bits` bits, like for the value of an integer.
+    fn parse(s: &str, offset: usize, radix: u32, num_bits: Option<usize>) -> Result<Self, Error> {
+        let width = radix.trailing_zeros() as usize;
+        let mut digits = BitVec::<Lsb0, LittleEndian>::with_capacity(s.len() * width);
+        for (index, character) in s.char_indices() {
+            if character == '_' || character.is_whitespace() {
+                continue;
+            }
+            let digit = character.to_digit(radix).ok_or(Error::InvalidDigit {
+                index: offset + index,
+                character,
+            })?;
+            digits.extend((0..width).rev().map(|i| digit >> i & 1 != 0));
+        }
+        let digits = digits.as_bitslice();
+        let len = num_bits.unwrap_or(digits.len());
+        let mut vec = Self::with_capacity(len);
+        if len < digits.len() {
+            if digits.slice(..digits.len() - len).first_one().is_some() {
+                return Err(Error::Overflow);
+            }
+            vec.extend(digits.slice(digits.len() - len..).iter());
+        } else {
+            vec.extend(core::iter::repeat_n(false, len - digits.len()));
+            vec.extend(digits.iter());
+        }
+        Ok(vec)
+    }
+    /// Splits off a `0b`, `0o` or `0x` prefix and returns the rest of `s`, its offset and the
+    /// radix. Strings without a prefix are binary.
+    fn split_radix(s: &str) -> (&str, usize, u32) {
+        let trimmed = s.trim_start();
+        let offset = s.len() - trimmed.len() + 2;
+        match trimmed.get(..2) {
+            Some("0b" | "0B") => (&trimmed[2..], offset, 2),
+            Some("0o" | "0O") => (&trimmed[2..], offset, 8),
+            Some("0x" | "0X") => (&trimmed[2..], offset, 16),
+            _ => (s, 0, 2),
+        }
+    }
+    /// Parses a string of binary digits with an optional `0b` prefix.
+    ///
+    /// # Returns
+    /// The bits, or [`Error::InvalidDigit`] if `s` contains a character other than `0`, `1` or a
+    /// separator.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitVec, Msb0, LittleEndian};
+    /// let bits: BitVec<Msb0, LittleEndian> = BitVec::from_bin_str("1011_0010 1").unwrap();
+    /// assert_eq!(bits.len(), 9);
+    /// assert_eq!(bits.as_bytes(), &[0b1011_0010, 0b1000_0000]);
+    /// assert!(BitVec::<Msb0, LittleEndian>::from_bin_str("10201").is_err());
+    /// ```
+    pub fn from_bin_str(s: &str) -> Result<Self, Error> {
+        match Self::split_radix(s) {
+            (digits, offset, 2) => Self::parse(digits, offset, 2, None),
+            _ => Self::parse(s, 0, 2, None),
+        }
+    }
+    /// Parses a string of octal digits with an optional `0o` prefix. Each digit is 3 bits.
+    ///
+    /// See [`BitVec::from_bin_str`].
+    pub fn from_oct_str(s: &str) -> Result<Self, Error> {
+        match Self::split_radix(s) {
+            (digits, offset, 8) => Self::parse(digits, offset, 8, None),
+            _ => Self::parse(s, 0, 8, None),
+        }
+    }
+    /// Parses a string of hexadecimal digits with an optional `0x` prefix. Each digit is 4 bits.
+    ///
+    /// See [`BitVec::from_bin_str`].
+    pub fn from_hex_str(s: &str) -> Result<Self, Error> {
+        match Self::split_radix(s) {
+            (digits, offset, 16) => Self::parse(digits, offset, 16, None),
+            _ => Self::parse(s, 0, 16, None),
+        }
+    }
+    /// Parses a string of digits with a `0b`, `0o` or `0x` prefix, or of binary digits without
+    /// a prefix, into exactly `num_bits` bits.
+    ///
+    /// Like for an integer, zeros are prepended if the digits have fewer bits, and leading bits
+    /// are dropped if they have more.
+    ///
+    /// # Returns
+    /// The bits, [`Error::InvalidDigit`] if `s` contains a character that is not a digit or a
+    /// separator, or [`Error::Overflow`] if a dropped bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitVec, Lsb0, LittleEndian};
+    /// let bits: BitVec<Lsb0, LittleEndian> = BitVec::from_str_with_len("0x1f", 6).unwrap();
+    /// assert_eq!(bits.as_bitslice().to_string(), "011111");
+    /// assert!(BitVec::<Lsb0, LittleEndian>::from_str_with_len("0x1f", 4).is_err());
+    /// ```
+    pub fn from_str_with_len(s: &str, num_bits: usize) -> Result<Self, Error> {
+        let (digits, offset, radix) = Self::split_radix(s);
+        Self::parse(digits, offset, radix, Some(num_bits))
+    }
+}
+
+/// Parses a string of digits with a `0b`, `0o` or `0x` prefix, or of binary digits without a
+/// prefix.
+///
+/// See [`BitVec::from_bin_str`] and [`BitVec::from_str_with_len`].
+impl<B: BitOrder + Default, Endian: ByteOrder + Default> core::str::FromStr for BitVec<B, Endian> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (digits, offset, radix) = Self::split_radix(s);
+        Self::parse(digits, offset, radix, None)
+    }
+}
+
+impl<B: BitOrder + Default, Endian: ByteOrder + Default> Default for BitVec<B, Endian> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Copy, Endian: Copy> Clone for BitVec<B, Endian> {
+    fn clone(&self) -> Self {
+        BitVec {
+            bytes: self.bytes.clone(),
+            ..*self
+        }
+    }
+}
+
+impl<B: BitOrder, Endian: ByteOrder> Extend<bool> for BitVec<B, Endian> {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<B: BitOrder + Default, Endian: ByteOrder + Default> FromIterator<bool> for BitVec<B, Endian> {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> From<&BitSlice<S, B, Endian>>
+    for BitVec<B, Endian>
+{
+    fn from(slice: &BitSlice<S, B, Endian>) -> Self {
+        let mut vec = Self::new_with_order(slice.bit_order, slice.byte_order);
+        vec.extend_from_bitslice(slice);
+        vec
+    }
+}
+
+impl<B: BitOrder, Endian: ByteOrder> PartialEq for BitVec<B, Endian> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bitslice() == other.as_bitslice()
+    }
+}
+
+impl<S: BitStorage, B: BitOrder, Endian: ByteOrder> PartialEq<BitSlice<S, B, Endian>>
+    for BitVec<B, Endian>
+{
+    #[inline(always)]
+    fn eq(&self, other: &BitSlice<S, B, Endian>) -> bool {
+        self.as_bitslice() == *other
+    }
+}
+
+impl<B, Endian> core::fmt::Debug for BitVec<B, Endian>
+where
+    B: BitOrder + core::fmt::Debug,
+    Endian: ByteOrder + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BitVec")
+            .field("bits", &format_args!("{}", self.as_bitslice()))
+            .field("bit_order", &self.bit_order)
+            .field("byte_order", &self.byte_order)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BigEndian, Msb0};
+
+    #[test]
+    fn test_push_pop() {
+        let bits = [
+            true, false, true, true, false, false, true, false, true, true,
+        ];
+        let mut vec: BitVec<Msb0, BigEndian> = bits.iter().copied().collect();
+        assert_eq!(vec.len(), 10);
+        // The first byte moves to the end of the storage as the vector grows.
+        assert_eq!(vec.as_bytes(), &[0xc0, 0xb2]);
+        assert_eq!((vec.pop(), vec.pop()), (Some(true), Some(true)));
+        assert_eq!(vec.as_bytes(), &[0xb2]);
+        assert_eq!((vec.pop(), vec.pop()), (Some(false), Some(true)));
+        // Popped bits are cleared.
+        assert_eq!(vec.as_bytes(), &[0xb0]);
+
+        let mut vec: BitVec<Lsb0, LittleEndian> = BitVec::new();
+        for bit in bits {
+            vec.push(bit);
+        }
+        assert_eq!(vec.as_bytes(), &[0x4d, 0x03]);
+        assert_eq!((vec.pop(), vec.pop()), (Some(true), Some(true)));
+        assert_eq!(vec.as_bytes(), &[0x4d]);
+        assert_eq!((vec.pop(), vec.pop()), (Some(false), Some(true)));
+        assert_eq!(vec.as_bytes(), &[0x0d]);
+        assert_eq!(vec.len(), 6);
+        assert_eq!(vec.pop(), Some(false));
+        assert_eq!(BitVec::<Lsb0, LittleEndian>::new().pop(), None);
+    }
+
+    #[test]
+    fn test_layout_matches_bitslice() {
+        let x = [0x12u8, 0x34, 0x56];
+        let slice: BitSlice<_, Lsb0, BigEndian> = x.as_ref().into();
+        let vec: BitVec<Lsb0, BigEndian> = slice.iter().collect();
+        assert_eq!(vec.as_bytes(), &x);
+        assert_eq!(vec, slice);
+        assert_eq!(BitVec::from(&slice), vec);
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut vec: BitVec<Msb0, BigEndian> = BitVec::new();
+        vec.extend((0..100).map(|i| i % 2 == 0));
+        vec.insert(0, false);
+        vec.insert(50, true);
+        vec.insert(102, true);
+        assert_eq!(vec.len(), 103);
+        assert!(!vec.get_bit(0));
+        assert!(vec.get_bit(50));
+        assert!(vec.get_bit(102));
+        assert!(vec.get_bit(1));
+        assert!(!vec.get_bit(101));
+        assert!(vec.remove(102));
+        assert!(vec.remove(50));
+        assert!(!vec.remove(0));
+        assert!(vec.as_bitslice().iter().eq((0..100).map(|i| i % 2 == 0)));
+        assert_eq!(
+            vec.try_insert(101, true),
+            Err(Error::IndexOutOfBounds {
+                index: 101,
+                len: 100
+            })
+        );
+        assert_eq!(
+            vec.try_remove(100),
+            Err(Error::IndexOutOfBounds {
+                index: 100,
+                len: 100
+            })
+        );
+        assert_eq!(vec.try_insert(100, true), Ok(()));
+        assert_eq!(vec.try_remove(100), Ok(true));
+        assert_eq!(vec.len(), 100);
+        assert_eq!(
+            vec.try_set_bit(100, true),
+            Err(Error::IndexOutOfBounds {
+                index: 100,
+                len: 100
+            })
+        );
+        assert_eq!(vec.try_set_bit(99, true), Ok(()));
+        assert_eq!(vec.get(99), Some(true));
+        assert_eq!(vec.get(100), None);
+    }
+
+    #[test]
+    fn test_truncate_append() {
+        let mut a: BitVec = (0..20).map(|_| true).collect();
+        let mut b: BitVec = (0..5).map(|_| false).collect();
+        a.truncate(10);
+        a.truncate(12);
+        assert_eq!(a.len(), 10);
+        assert_eq!(a.as_bytes(), &[0xff, 0x03]);
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(a.len(), 15);
+        assert_eq!(a.as_bitslice().get_field::<u16>(..), Ok(0x3ff));
+        a.clear();
+        assert!(a.is_empty());
+        assert!(a.capacity() >= 20);
+    }
+
+    #[test]
+    fn test_parse() {
+        let bits: BitVec<Msb0, BigEndian> = "0b1011_0010\t0111".parse().unwrap();
+        assert_eq!(bits.as_bitslice().to_string(), "101100100111");
+        let bits: BitVec<Lsb0, BigEndian> = " 0xb2_7".parse().unwrap();
+        assert_eq!(bits.as_bitslice().to_string(), "101100100111");
+        let bits: BitVec<Lsb0, BigEndian> = "0o5447".parse().unwrap();
+        assert_eq!(bits.as_bitslice().to_string(), "101100100111");
+        assert_eq!(
+            BitVec::<Lsb0, BigEndian>::from_hex_str("B27"),
+            Ok(bits.clone())
+        );
+        assert_eq!(
+            BitVec::<Msb0, BigEndian>::from_oct_str("0o5447")
+                .unwrap()
+                .len(),
+            12
+        );
+        assert!("".parse::<BitVec>().unwrap().is_empty());
+
+        // Text round-trips through `to_string`.
+        let bits: BitVec<Lsb0, LittleEndian> = (0..77).map(|i| i % 3 == 0).collect();
+        let text = bits.as_bitslice().to_string();
+        assert_eq!(BitVec::from_bin_str(&text), Ok(bits));
+
+        let bits: BitVec = BitVec::from_str_with_len("0x0f", 5).unwrap();
+        assert_eq!(bits.as_bitslice().to_string(), "01111");
+        let bits: BitVec = BitVec::from_str_with_len("101", 6).unwrap();
+        assert_eq!(bits.as_bitslice().to_string(), "000101");
+        assert_eq!(
+            BitVec::<Lsb0>::from_str_with_len("0x1f", 4),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "0b1021".parse::<BitVec>(),
+            Err(Error::InvalidDigit {
+                index: 4,
+                character: '2'
+            })
+        );
+        assert_eq!(
+            BitVec::<Lsb0>::from_hex_str("  0xfg"),
+            Err(Error::InvalidDigit {
+                index: 5,
+                character: 'g'
+            })
+        );
+        assert_eq!(
+            BitVec::<Lsb0>::from_bin_str("0x10"),
+            Err(Error::InvalidDigit {
+                index: 1,
+                character: 'x'
+            })
+        );
+        assert_eq!(
+            BitVec::<Lsb0>::from_oct_str("1 2 8"),
+            Err(Error::InvalidDigit {
+                index: 4,
+                character: '8'
+            })
+        );
+    }
+}
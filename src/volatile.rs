@@ -0,0 +1,334 @@
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+use core::ptr::NonNull;
+
+use crate::{
+    range_to_bounds, words_as_bytes_mut, BitOrder, BitSlice, BitStore, Error, Lsb0, Signed,
+    Unsigned, Words,
+};
+
+/// The maximum number of bits accessed at a time, enough for any integer field.
+const MAX_BITS: usize = 128;
+/// The maximum number of words holding [`MAX_BITS`] bits, reached with `u8` words.
+const MAX_WORDS: usize = MAX_BITS / 8 + 1;
+
+/// A region of memory-mapped registers made of `W` words, whose bits are accessed with volatile
+/// reads and writes.
+///
+/// The bits are laid out like in a [`BitSlice`] with the [`Words`] byte order, so they are
+/// numbered word by word and within a word according to `B`.
+///
+/// Every access reads the words holding the accessed bits with
+/// [`read_volatile`](core::ptr::read_volatile) into a local copy and works on that with the
+/// methods of [`BitSlice`]. Writes then store the words back with
+/// [`write_volatile`](core::ptr::write_volatile), so a field is updated with a single
+/// read-modify-write of the words holding it, and no other word is touched. Accesses cover at
+/// most 128 bits.
+///
+/// # Examples
+/// Registers are usually accessed at a fixed address:
+/// ```no_run
+/// use bitslicer::{Lsb0, VolatileBits};
+/// // SAFETY: The peripheral has four 32-bit registers at this address.
+/// let mut regs: VolatileBits<u32, Lsb0> =
+///     unsafe { VolatileBits::new(0x4000_1000 as *mut u32, 4) };
+/// regs.set_field(36..40, 0x5u8).unwrap();
+/// ```
+///
+/// Any memory can serve as the registers, e.g. in tests:
+/// ```
+/// use bitslicer::{Lsb0, VolatileBits};
+/// let mut regs = [0u32; 2];
+/// let mut bits: VolatileBits<_, Lsb0> = VolatileBits::from_mut(&mut regs);
+/// bits.set_field(4..8, 0xau8).unwrap();
+/// bits.set_bit(32, true);
+/// assert_eq!(bits.get_field::<u8>(0..8), Ok(0xa0));
+/// assert_eq!(regs, [0xa0, 0x1]);
+/// ```
+pub struct VolatileBits<'a, W, B = Lsb0> {
+    ptr: NonNull<W>,
+    /// The number of words.
+    len: usize,
+    bit_order: B,
+    _marker: PhantomData<&'a mut [W]>,
+}
+
+impl<'a, W: BitStore, B: BitOrder> VolatileBits<'a, W, B> {
+    /// Creates a region of `len` words starting at `ptr` with default bit order.
+    ///
+    /// # Safety
+    /// See [`VolatileBits::new_with_order`].
+    #[inline(always)]
+    pub unsafe fn new(ptr: *mut W, len: usize) -> Self
+    where
+        B: Default,
+    {
+        unsafe { Self::new_with_order(ptr, len, B::default()) }
+    }
+    /// Creates a region of `len` words starting at `ptr` with a specific bit order.
+    ///
+    /// # Safety
+    /// `ptr` must be non-null, aligned for `W` and valid for volatile reads and writes of `len`
+    /// words for the lifetime `'a`, and the words must not be accessed through references while
+    /// the region is in use.
+    #[inline(always)]
+    pub unsafe fn new_with_order(ptr: *mut W, len: usize, bit_order: B) -> Self {
+        Self {
+            // SAFETY: The caller guarantees that `ptr` is non-null.
+            ptr: unsafe { NonNull::new_unchecked(ptr) },
+            len,
+            bit_order,
+            _marker: PhantomData,
+        }
+    }
+    /// Creates a region over a slice of words with default bit order.
+    #[inline(always)]
+    pub fn from_mut(words: &'a mut [W]) -> Self
+    where
+        B: Default,
+    {
+        // SAFETY: The slice is valid and borrowed mutably for `'a`.
+        unsafe { Self::new(words.as_mut_ptr(), words.len()) }
+    }
+    /// Returns the number of bits in the region.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len * W::BITS
+    }
+    /// Returns `true` if the region contains no bits.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Reads the words holding the bits in `range`, calls `f` with a [`BitSlice`] over these
+    /// bits and, if `write` is `true` and `f` succeeds, writes the words back.
+    fn access<R>(
+        &self,
+        range: impl RangeBounds<usize>,
+        write: bool,
+        f: impl FnOnce(&mut BitSlice<&mut [u8], B, Words<W>>) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        let (start, end) = range_to_bounds(
+            range.start_bound().cloned(),
+            range.end_bound().cloned(),
+            self.len(),
+        )?;
+        if end - start > MAX_BITS {
+            return Err(Error::StorageTooSmall {
+                bits: end - start,
+                capacity: MAX_BITS,
+            });
+        }
+        let (first, count, offset) = if start == end {
+            (0, 0, 0)
+        } else {
+            let first = start / W::BITS;
+            (first, end.div_ceil(W::BITS) - first, start % W::BITS)
+        };
+        let mut words = [W::default(); MAX_WORDS];
+        for (i, word) in words[..count].iter_mut().enumerate() {
+            // SAFETY: Word `first + i` lies within the region, which the creator of the region
+            // guaranteed to be valid for volatile reads.
+            *word = unsafe { self.ptr.as_ptr().add(first + i).read_volatile() };
+        }
+        let mut bits = BitSlice {
+            bytes: words_as_bytes_mut(&mut words[..count]),
+            range: offset..offset + end - start,
+            bit_order: self.bit_order,
+            byte_order: Words::default(),
+        };
+        let result = f(&mut bits)?;
+        if write {
+            for (i, &word) in words[..count].iter().enumerate() {
+                // SAFETY: As above, and the region is valid for volatile writes as well.
+                unsafe { self.ptr.as_ptr().add(first + i).write_volatile(word) };
+            }
+        }
+        Ok(result)
+    }
+    /// Reads the bits in `range` and calls `f` with a [`BitSlice`] over them.
+    ///
+    /// # Returns
+    /// The result of `f`, [`Error::RangeOutOfBounds`] if `range` is out of bounds or
+    /// [`Error::StorageTooSmall`] if it has more than 128 bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitPack, Msb0, VolatileBits};
+    /// let mut regs = [0x1234u16, 0x5678];
+    /// let bits: VolatileBits<_, Msb0> = VolatileBits::from_mut(&mut regs);
+    /// assert_eq!(bits.read(8..24, |bits| u16::unpack(bits)), Ok(0x3456));
+    /// ```
+    pub fn read<R>(
+        &self,
+        range: impl RangeBounds<usize>,
+        f: impl FnOnce(&BitSlice<&[u8], B, Words<W>>) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        self.access(range, false, |bits| f(&bits.slice(..)))
+    }
+    /// Reads the bits in `range`, calls `f` with a mutable [`BitSlice`] over them and writes
+    /// them back if `f` succeeds.
+    ///
+    /// Only the words holding bits in `range` are read and written, each of them once, and bits
+    /// outside of `range` keep their value.
+    ///
+    /// # Returns
+    /// The result of `f`, [`Error::RangeOutOfBounds`] if `range` is out of bounds or
+    /// [`Error::StorageTooSmall`] if it has more than 128 bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BitPack, Lsb0, VolatileBits};
+    /// let mut regs = [0xffff_ffffu32];
+    /// let mut bits: VolatileBits<_, Lsb0> = VolatileBits::from_mut(&mut regs);
+    /// bits.modify(8..24, |bits| {
+    ///     bits.set_field(..8, 0x00u8)?;
+    ///     0x12u8.pack(&mut bits.slice_mut(8..))
+    /// })
+    /// .unwrap();
+    /// assert_eq!(regs, [0xff12_00ff]);
+    /// ```
+    pub fn modify<R>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        f: impl FnOnce(&mut BitSlice<&mut [u8], B, Words<W>>) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        self.access(range, true, f)
+    }
+    /// Reads the value of a bit.
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds.
+    #[track_caller]
+    pub fn get_bit(&self, n: usize) -> bool {
+        assert!(
+            n < self.len(),
+            "{}",
+            Error::IndexOutOfBounds {
+                index: n,
+                len: self.len()
+            }
+        );
+        self.read(n..n + 1, |bits| Ok(bits.get_bit(0))) == Ok(true)
+    }
+    /// Sets the value of a bit with a read-modify-write of its word.
+    ///
+    /// # Panics
+    /// Panics if `n` is out of bounds.
+    #[track_caller]
+    pub fn set_bit(&mut self, n: usize, value: bool) {
+        assert!(
+            n < self.len(),
+            "{}",
+            Error::IndexOutOfBounds {
+                index: n,
+                len: self.len()
+            }
+        );
+        let _ = self.modify(n..n + 1, |bits| {
+            bits.set_bit(0, value);
+            Ok(())
+        });
+    }
+    /// Reads an unsigned integer from a range of bits, see [`BitSlice::get_field`].
+    pub fn get_field<T: Unsigned>(&self, range: impl RangeBounds<usize>) -> Result<T, Error> {
+        self.read(range, |bits| bits.get_field(..))
+    }
+    /// Reads a signed integer from a range of bits, see [`BitSlice::get_signed_field`].
+    pub fn get_signed_field<T: Signed>(&self, range: impl RangeBounds<usize>) -> Result<T, Error> {
+        self.read(range, |bits| bits.get_signed_field(..))
+    }
+    /// Writes an unsigned integer into a range of bits with a read-modify-write of the words
+    /// holding them, see [`BitSlice::set_field`].
+    ///
+    /// Nothing is written if `value` does not fit.
+    pub fn set_field<T: Unsigned>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        self.modify(range, |bits| bits.set_field(.., value))
+    }
+    /// Writes a signed integer into a range of bits with a read-modify-write of the words
+    /// holding them, see [`BitSlice::set_signed_field`].
+    ///
+    /// Nothing is written if `value` does not fit.
+    pub fn set_signed_field<T: Signed>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: T,
+    ) -> Result<(), Error> {
+        self.modify(range, |bits| bits.set_signed_field(.., value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Lsb0, Msb0, VolatileBits};
+
+    #[test]
+    fn test_fields() {
+        let mut regs = [0u32; 3];
+        let mut bits: VolatileBits<_, Lsb0> = VolatileBits::from_mut(&mut regs);
+        assert_eq!(bits.len(), 96);
+        bits.set_field(28..40, 0xabcu16).unwrap();
+        bits.set_signed_field(64..72, -2i8).unwrap();
+        bits.set_bit(95, true);
+        assert!(bits.get_bit(95));
+        assert!(!bits.get_bit(94));
+        assert_eq!(bits.get_field::<u16>(28..40), Ok(0xabc));
+        assert_eq!(bits.get_signed_field::<i8>(64..72), Ok(-2));
+        assert_eq!(bits.get_field::<u8>(28..40), Err(Error::ConversionError));
+        assert_eq!(regs, [0xc000_0000, 0x0000_00ab, 0x8000_00fe]);
+
+        let mut regs = [0u16; 2];
+        let mut bits: VolatileBits<_, Msb0> = VolatileBits::from_mut(&mut regs);
+        bits.set_field(12..20, 0xa5u8).unwrap();
+        assert_eq!(regs, [0x000a, 0x5000]);
+    }
+
+    #[test]
+    fn test_raw_pointer() {
+        let mut memory = [0x0123_4567_89ab_cdefu64, 0];
+        // SAFETY: `memory` outlives the region and is only accessed through it meanwhile.
+        let mut regs: VolatileBits<u64, Msb0> =
+            unsafe { VolatileBits::new(memory.as_mut_ptr(), memory.len()) };
+        assert_eq!(regs.get_field::<u16>(..16), Ok(0x0123));
+        regs.modify(60.., |bits| {
+            bits.invert();
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(memory, [0x0123_4567_89ab_cde0, u64::MAX]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut regs = [0xffu8; 20];
+        let mut bits: VolatileBits<_> = VolatileBits::from_mut(&mut regs);
+        assert_eq!(
+            bits.set_field(150..170, 0u8),
+            Err(Error::RangeOutOfBounds {
+                start: 150,
+                end: 170,
+                len: 160
+            })
+        );
+        assert_eq!(
+            bits.read(..129, |_| Ok(())),
+            Err(Error::StorageTooSmall {
+                bits: 129,
+                capacity: 128
+            })
+        );
+        // A field that does not fit leaves the words unchanged.
+        assert_eq!(bits.set_field(4..8, 0x10u8), Err(Error::Overflow));
+        assert_eq!(bits.modify(0..0, |bits| Ok(bits.len())), Ok(0));
+        // The largest accesses cover one word more than they have bits.
+        bits.modify(4..132, |bits| bits.set_field(.., 0u128))
+            .unwrap();
+        assert_eq!(regs[0], 0x0f);
+        assert_eq!(regs[1..16], [0; 15]);
+        assert_eq!(regs[16..], [0xf0, 0xff, 0xff, 0xff]);
+    }
+}
@@ -0,0 +1,343 @@
+use crate::{
+    BitOrder, BitSlice, BitStorage, ByteOrder, Error, LittleEndian, Lsb0, Signed, Unsigned,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The `WriteStorage` trait is implemented by all storage types a [`BitWriter`] can write into.
+///
+/// Byte slices and arrays have a fixed size, so writes past their end fail. With the `alloc`
+/// feature `Vec<u8>` grows as needed.
+pub trait WriteStorage: AsRef<[u8]> + AsMut<[u8]> + private::Sealed {
+    /// Makes sure the storage holds at least `len` bytes and returns `false` if it cannot.
+    ///
+    /// Bytes are added where `byte_order` places the bytes following the existing ones.
+    #[doc(hidden)]
+    fn grow(&mut self, len: usize, byte_order: impl ByteOrder) -> bool;
+    /// Removes all but the first `len` bytes in byte order, if the storage supports it.
+    #[doc(hidden)]
+    fn trim(&mut self, len: usize, byte_order: impl ByteOrder);
+}
+
+impl private::Sealed for &mut [u8] {}
+impl WriteStorage for &mut [u8] {
+    #[inline(always)]
+    fn grow(&mut self, len: usize, _byte_order: impl ByteOrder) -> bool {
+        self.len() >= len
+    }
+    #[inline(always)]
+    fn trim(&mut self, _len: usize, _byte_order: impl ByteOrder) {}
+}
+impl<const N: usize> private::Sealed for [u8; N] {}
+impl<const N: usize> WriteStorage for [u8; N] {
+    #[inline(always)]
+    fn grow(&mut self, len: usize, _byte_order: impl ByteOrder) -> bool {
+        N >= len
+    }
+    #[inline(always)]
+    fn trim(&mut self, _len: usize, _byte_order: impl ByteOrder) {}
+}
+#[cfg(feature = "alloc")]
+impl private::Sealed for alloc::vec::Vec<u8> {}
+#[cfg(feature = "alloc")]
+impl WriteStorage for alloc::vec::Vec<u8> {
+    fn grow(&mut self, len: usize, byte_order: impl ByteOrder) -> bool {
+        let old = self.len();
+        if old >= len {
+            return true;
+        }
+        let new = len
+            .next_multiple_of(byte_order.word_bytes())
+            .max(old * 2)
+            .max(8);
+        if !byte_order.starts_at_end() {
+            self.resize(new, 0);
+        } else {
+            // The first bytes are at the end of the storage, so the new ones go to the front.
+            self.splice(0..0, core::iter::repeat_n(0, new - old));
+        }
+        true
+    }
+    fn trim(&mut self, len: usize, byte_order: impl ByteOrder) {
+        let (old, len) = (self.len(), len.next_multiple_of(byte_order.word_bytes()));
+        if !byte_order.starts_at_end() {
+            self.truncate(len);
+        } else {
+            self.drain(..old - len);
+        }
+    }
+}
+
+/// A cursor that writes bits, integers and [`BitSlice`]s into a storage one after another.
+///
+/// The bits are laid out like in a [`BitSlice`] with the same bit and byte order, and integers
+/// are written like [`BitSlice::set_field`] writes them, so the output can be read back with a
+/// [`BitReader`](crate::BitReader) with the same orders.
+///
+/// Every write checks that the value fits and that there is room for it, and leaves the
+/// position unchanged otherwise. Bits that are not written keep their previous value, except
+/// for the rest of the last byte, which is cleared by [`BitWriter::finish`] and
+/// [`BitWriter::into_inner`].
+///
+/// # Type Parameters
+/// - `S`: The underlying storage type, see [`WriteStorage`].
+/// - `B`: The bit order, which dictates the order in which bits are written.
+/// - `Endian`: The byte order, which dictates the order in which bytes are written.
+///
+/// # Examples
+/// ```
+/// use bitslicer::{BitWriter, LittleEndian, Msb0};
+/// let mut data = [0xffu8; 4];
+/// let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new(data.as_mut());
+/// writer.write_bit(true).unwrap();
+/// writer.write(0b011u8, 3).unwrap();
+/// writer.write_signed(-2i8, 6).unwrap();
+/// assert_eq!(writer.position(), 10);
+/// assert_eq!(writer.finish(), 2);
+/// assert_eq!(data, [0b1011_1111, 0b1000_0000, 0xff, 0xff]);
+/// ```
+pub struct BitWriter<S, B = Lsb0, Endian = LittleEndian> {
+    bytes: S,
+    pub(crate) pos: usize,
+    bit_order: B,
+    byte_order: Endian,
+}
+
+impl<S: WriteStorage, B: BitOrder, Endian: ByteOrder> BitWriter<S, B, Endian> {
+    /// Creates a new [BitWriter] with default bit and byte order that starts writing at the
+    /// first bit of `bytes`.
+    #[inline(always)]
+    pub fn new(bytes: S) -> Self
+    where
+        B: Default,
+        Endian: Default,
+    {
+        Self::new_with_order(bytes, Default::default(), Default::default())
+    }
+    /// Creates a new [BitWriter] with specific bit and byte order that starts writing at the
+    /// first bit of `bytes`.
+    ///
+    /// # Arguments
+    /// * `bytes` - The storage to write into.
+    /// * `bit_order` - The bit order to use.
+    /// * `endianness` - The byte order to use.
+    ///
+    /// # Panics
+    /// Panics if `bytes` does not consist of whole words of the byte order, see [`Words`]. See
+    /// [`BitWriter::try_new_with_order`] for a non-panicking version.
+    ///
+    /// [`Words`]: crate::Words
+    #[inline(always)]
+    #[track_caller]
+    pub fn new_with_order(bytes: S, bit_order: B, endianness: Endian) -> Self {
+        Self::try_new_with_order(bytes, bit_order, endianness).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Creates a new [BitWriter] with specific bit and byte order, or returns
+    /// [`Error::PartialWord`] if `bytes` does not consist of whole words of the byte order.
+    ///
+    /// See [`BitWriter::new_with_order`].
+    pub fn try_new_with_order(bytes: S, bit_order: B, endianness: Endian) -> Result<Self, Error> {
+        let len = bytes.as_ref().len();
+        if !len.is_multiple_of(Endian::WORD_BYTES) {
+            return Err(Error::PartialWord {
+                len,
+                word_bytes: Endian::WORD_BYTES,
+            });
+        }
+        Ok(Self {
+            bytes,
+            pos: 0,
+            bit_order,
+            byte_order: endianness,
+        })
+    }
+    /// Returns the number of bits written so far.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+    /// Returns `true` if the position is a multiple of 8 bits from the start of the storage.
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool {
+        self.pos.is_multiple_of(8)
+    }
+    /// Returns a [BitSlice] over the bits written so far.
+    pub fn as_bitslice(&self) -> BitSlice<&[u8], B, Endian> {
+        let bytes = self.bytes.as_ref();
+        BitSlice {
+            bytes,
+            range: 0..self.pos,
+            bit_order: self.bit_order,
+            byte_order: self.byte_order,
+        }
+    }
+    /// Makes room for `n` more bits and returns a mutable [BitSlice] over the whole storage.
+    pub(crate) fn reserve(&mut self, n: usize) -> Result<BitSlice<&mut [u8], B, Endian>, Error> {
+        if !self.bytes.grow((self.pos + n).div_ceil(8), self.byte_order) {
+            return Err(Error::EndOfData {
+                requested: n,
+                remaining: self.bytes.as_ref().len() * 8 - self.pos,
+            });
+        }
+        let bytes = self.bytes.as_mut();
+        Ok(BitSlice {
+            range: 0..bytes.len() * 8,
+            bytes,
+            bit_order: self.bit_order,
+            byte_order: self.byte_order,
+        })
+    }
+    /// Writes a single bit.
+    pub fn write_bit(&mut self, value: bool) -> Result<(), Error> {
+        let pos = self.pos;
+        self.reserve(1)?.set_bit(pos, value);
+        self.pos += 1;
+        Ok(())
+    }
+    /// Writes `value` as an unsigned integer of `n` bits.
+    ///
+    /// # Returns
+    /// [`Error::Overflow`] if `value` does not fit into `n` bits or [`Error::EndOfData`] if the
+    /// storage has fewer than `n` bits left.
+    pub fn write<T: Unsigned>(&mut self, value: T, n: usize) -> Result<(), Error> {
+        let pos = self.pos;
+        self.reserve(n)?.set_field(pos..pos + n, value)?;
+        self.pos += n;
+        Ok(())
+    }
+    /// Writes `value` as a two's complement signed integer of `n` bits.
+    ///
+    /// # Returns
+    /// [`Error::Overflow`] if `value` does not fit into `n` bits or [`Error::EndOfData`] if the
+    /// storage has fewer than `n` bits left.
+    pub fn write_signed<T: Signed>(&mut self, value: T, n: usize) -> Result<(), Error> {
+        let pos = self.pos;
+        self.reserve(n)?.set_signed_field(pos..pos + n, value)?;
+        self.pos += n;
+        Ok(())
+    }
+    /// Writes all bits of `bits`.
+    ///
+    /// # Returns
+    /// [`Error::EndOfData`] if the storage has fewer bits left than `bits` has.
+    pub fn write_bits<S2: BitStorage>(
+        &mut self,
+        bits: &BitSlice<S2, B, Endian>,
+    ) -> Result<(), Error> {
+        let (pos, len) = (self.pos, bits.len());
+        let mut slice = self.reserve(len)?;
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(64);
+            slice.write_bits(pos + i, n, bits.read_bits(i, n));
+            i += n;
+        }
+        self.pos += len;
+        Ok(())
+    }
+    /// Writes `fill` until the position is a multiple of 8 bits. Does nothing if the position is
+    /// already aligned.
+    pub fn pad_to_byte(&mut self, fill: bool) -> Result<(), Error> {
+        let (pos, n) = (self.pos, self.pos.next_multiple_of(8) - self.pos);
+        self.reserve(n)?.fill_bits(pos..pos + n, fill);
+        self.pos += n;
+        Ok(())
+    }
+    /// Clears the rest of the last byte and returns the number of bytes written.
+    ///
+    /// The bytes written are the first ones of the storage only with [`LittleEndian`]. With
+    /// [`BigEndian`](crate::BigEndian) the first bit is in the last byte, so the bytes written are
+    /// the last ones of the storage, and with [`Words`](crate::Words) they are spread over the
+    /// first `n.div_ceil(size_of::<W>())` words.
+    ///
+    /// # Examples
+    /// ```
+    /// use bitslicer::{BigEndian, BitWriter, Msb0};
+    /// let mut data = [0u8; 4];
+    /// let mut writer: BitWriter<_, Msb0, BigEndian> = BitWriter::new(data.as_mut());
+    /// writer.write(0xabcu16, 12).unwrap();
+    /// let n = writer.finish();
+    /// assert_eq!(&data[data.len() - n..], [0xc0, 0xab]);
+    /// ```
+    pub fn finish(mut self) -> usize {
+        // The rest of the last byte is always part of the storage.
+        let _ = self.pad_to_byte(false);
+        self.pos / 8
+    }
+    /// Clears the rest of the last byte and returns the storage.
+    ///
+    /// A `Vec<u8>` is shortened to the bytes that were written, or to the words that were
+    /// written with [`Words`](crate::Words).
+    pub fn into_inner(mut self) -> S {
+        let len = self.pos.div_ceil(8);
+        let _ = self.pad_to_byte(false);
+        self.bytes.trim(len, self.byte_order);
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bits, BigEndian, Msb0};
+    extern crate alloc;
+    extern crate std;
+
+    #[test]
+    fn test_writes() {
+        let mut data = [0xa5u8; 5];
+        let mut writer: BitWriter<_, Msb0, LittleEndian> = BitWriter::new(data.as_mut());
+        writer.write_bit(true).unwrap();
+        writer.write(0x274u16, 12).unwrap();
+        writer.write_signed(-5i8, 4).unwrap();
+        writer.pad_to_byte(true).unwrap();
+        writer.write_bits(&bits![0, 1]).unwrap();
+        writer.write(0u8, 0).unwrap();
+        assert_eq!(writer.position(), 26);
+        assert_eq!(writer.finish(), 4);
+        // The rest of the last byte is cleared and the bytes after it are not touched.
+        assert_eq!(data, [0x93, 0xa5, 0xff, 0x40, 0xa5]);
+
+        // With `Lsb0` the least significant bit of a value is written first.
+        let mut data = [0xffu8; 3];
+        let mut writer: BitWriter<_, Lsb0, BigEndian> = BitWriter::new(data.as_mut());
+        writer.write(0b101u8, 3).unwrap();
+        writer.write_signed(-2i8, 4).unwrap();
+        writer.write_bit(true).unwrap();
+        writer.write(0xabcu16, 12).unwrap();
+        assert_eq!(writer.finish(), 3);
+        assert_eq!(data, [0x0a, 0xbc, 0xf5]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_vec() {
+        let mut writer: BitWriter<_, Msb0, BigEndian> = BitWriter::new(alloc::vec::Vec::new());
+        writer.write(0xabcu16, 12).unwrap();
+        assert!(writer.as_bitslice() == bits![1, 0, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0]);
+        assert_eq!(writer.into_inner(), [0xc0, 0xab]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut writer: BitWriter<_> = BitWriter::new([0u8; 2]);
+        writer.write(3u8, 3).unwrap();
+        assert_eq!(writer.write(8u8, 3), Err(Error::Overflow));
+        assert_eq!(writer.write_signed(4i8, 3), Err(Error::Overflow));
+        assert_eq!(
+            writer.write(0u16, 14),
+            Err(Error::EndOfData {
+                requested: 14,
+                remaining: 13
+            })
+        );
+        assert_eq!(writer.position(), 3);
+        writer.pad_to_byte(true).unwrap();
+        writer.pad_to_byte(false).unwrap();
+        writer.write(0xabu8, 8).unwrap();
+        assert!(writer.write_bit(false).is_err());
+        assert_eq!(writer.into_inner(), [0xfb, 0xab]);
+    }
+}